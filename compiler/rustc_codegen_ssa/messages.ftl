@@ -10,6 +10,10 @@ codegen_ssa_atomic_compare_exchange = Atomic compare-exchange intrinsic missing
 
 codegen_ssa_binary_output_to_tty = option `-o` or `--emit` is used to write binary output type `{$shorthand}` to stdout, but stdout is a tty
 
+codegen_ssa_cdylib_links_std_dynamically = this `cdylib` links dynamically against `std`
+    .note = the Rust standard library shared object must be present wherever the library is loaded
+    .help = `crate-type = "staticlib"` is usually preferred for C-compatible libraries
+
 codegen_ssa_cgu_not_recorded =
     CGU-reuse for `{$cgu_user_name}` is (mangled: `{$cgu_name}`) was not recorded
 
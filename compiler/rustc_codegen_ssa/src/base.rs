@@ -15,6 +15,7 @@
 use rustc_middle::bug;
 use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrs;
 use rustc_middle::middle::debugger_visualizer::{DebuggerVisualizerFile, DebuggerVisualizerType};
+use rustc_middle::middle::dependency_format::Linkage;
 use rustc_middle::middle::exported_symbols::SymbolExportKind;
 use rustc_middle::middle::{exported_symbols, lang_items};
 use rustc_middle::mir::mono::{CodegenUnit, CodegenUnitNameBuilder, MonoItem};
@@ -23,6 +24,7 @@
 use rustc_middle::ty::layout::{HasTyCtxt, LayoutOf, TyAndLayout};
 use rustc_middle::ty::{self, Instance, Ty, TyCtxt};
 use rustc_session::config::{self, CrateType, EntryFnType, OptLevel, OutputType};
+use rustc_session::lint::builtin::DYNAMIC_STD_IN_CDYLIB;
 use rustc_session::Session;
 use rustc_span::symbol::sym;
 use rustc_span::{Symbol, DUMMY_SP};
@@ -914,6 +916,21 @@ pub fn new(tcx: TyCtxt<'_>, target_cpu: String) -> CrateInfo {
             }
         }
 
+        // A `cdylib` is usually loaded by code that isn't written in Rust, so linking `std`
+        // dynamically is most likely unintentional unless `-C prefer-dynamic` asked for it.
+        if !tcx.sess.opts.cg.prefer_dynamic
+            && let Some((_, linkage)) =
+                info.dependency_formats.iter().find(|(ty, _)| *ty == CrateType::Cdylib)
+            && let Some(&std) = crates.iter().find(|&&cnum| tcx.crate_name(cnum) == sym::std)
+            && linkage.get(std.as_usize() - 1) == Some(&Linkage::Dynamic)
+        {
+            tcx.emit_node_lint(
+                DYNAMIC_STD_IN_CDYLIB,
+                rustc_hir::CRATE_HIR_ID,
+                errors::CdylibLinksStdDynamically,
+            );
+        }
+
         // Handle circular dependencies in the standard library.
         // See comment before `add_linked_symbol_object` function for the details.
         // If global LTO is enabled then almost everything (*) is glued into a single object file,
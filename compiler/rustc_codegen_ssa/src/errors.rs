@@ -9,7 +9,7 @@
 use rustc_errors::{
     Diag, DiagArgValue, DiagCtxtHandle, Diagnostic, EmissionGuarantee, IntoDiagArg, Level,
 };
-use rustc_macros::Diagnostic;
+use rustc_macros::{Diagnostic, LintDiagnostic};
 use rustc_middle::ty::layout::LayoutError;
 use rustc_middle::ty::Ty;
 use rustc_span::{Span, Symbol};
@@ -30,6 +30,12 @@ pub struct IncorrectCguReuseType<'a> {
     pub at_least: u8,
 }
 
+#[derive(LintDiagnostic)]
+#[diag(codegen_ssa_cdylib_links_std_dynamically)]
+#[note]
+#[help]
+pub struct CdylibLinksStdDynamically;
+
 #[derive(Diagnostic)]
 #[diag(codegen_ssa_cgu_not_recorded)]
 pub struct CguNotRecorded<'a> {
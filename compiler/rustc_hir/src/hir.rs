@@ -15,7 +15,7 @@
 use rustc_index::IndexVec;
 use rustc_macros::{Decodable, Encodable, HashStable_Generic};
 use rustc_span::def_id::LocalDefId;
-use rustc_span::hygiene::MacroKind;
+use rustc_span::hygiene::{ExpnKind, MacroKind};
 use rustc_span::source_map::Spanned;
 use rustc_span::symbol::{kw, sym, Ident, Symbol};
 use rustc_span::{BytePos, ErrorGuaranteed, Span, DUMMY_SP};
@@ -1708,13 +1708,39 @@ pub struct ConstBlock {
 /// the compiler and the reference.
 ///
 /// [rust lang reference]: https://doc.rust-lang.org/reference/expressions.html
-#[derive(Debug, Clone, Copy, HashStable_Generic)]
+#[derive(Clone, Copy, HashStable_Generic)]
 pub struct Expr<'hir> {
     pub hir_id: HirId,
     pub kind: ExprKind<'hir>,
     pub span: Span,
 }
 
+impl fmt::Debug for Expr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The desugaring of `format_args!` is large and rarely of interest (e.g. in
+        // `-Zunpretty=hir-tree`), so show the macro call it originates from instead.
+        if self.span.from_expansion()
+            && let expn_data = self.span.ctxt().outer_expn_data()
+            && let ExpnKind::Macro(MacroKind::Bang, name) = expn_data.kind
+            && matches!(name, sym::format_args | sym::format_args_nl | sym::const_format_args)
+            && let Some(snippet) = rustc_span::debug_snippet(expn_data.call_site.source_callsite())
+        {
+            return f
+                .debug_struct("Expr")
+                .field("hir_id", &self.hir_id)
+                .field("kind", &debug_fn(|f| f.debug_tuple("FormatArgs").field(&snippet).finish()))
+                .field("span", &self.span)
+                .finish();
+        }
+
+        f.debug_struct("Expr")
+            .field("hir_id", &self.hir_id)
+            .field("kind", &self.kind)
+            .field("span", &self.span)
+            .finish()
+    }
+}
+
 impl Expr<'_> {
     pub fn precedence(&self) -> ExprPrecedence {
         match self.kind {
@@ -235,6 +235,9 @@ lint_deprecated_where_clause_location = where clause not allowed here
 lint_diag_out_of_impl =
     diagnostics should only be created in `Diagnostic`/`Subdiagnostic`/`LintDiagnostic` impls
 
+lint_diag_with_dummy_span = diagnostics should not be emitted with `DUMMY_SP`
+    .help = use the span of the code the diagnostic is about
+
 lint_drop_glue =
     types that do not implement `Drop` can still have drop glue, consider instead using `{$needs_drop}` to detect whether a type is trivially dropped
 
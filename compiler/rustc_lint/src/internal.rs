@@ -2,7 +2,7 @@
 //! Clippy.
 
 use rustc_ast as ast;
-use rustc_hir::def::Res;
+use rustc_hir::def::{DefKind, Res};
 use rustc_hir::def_id::DefId;
 use rustc_hir::{
     BinOp, BinOpKind, Expr, ExprKind, GenericArg, HirId, Impl, Item, ItemKind, Node, Pat, PatKind,
@@ -16,9 +16,10 @@
 use tracing::debug;
 
 use crate::lints::{
-    BadOptAccessDiag, DefaultHashTypesDiag, DiagOutOfImpl, LintPassByHand, NonExistentDocKeyword,
-    NonGlobImportTypeIrInherent, QueryInstability, QueryUntracked, SpanUseEqCtxtDiag, TyQualified,
-    TykindDiag, TykindKind, TypeIrInherentUsage, UntranslatableDiag,
+    BadOptAccessDiag, DefaultHashTypesDiag, DiagOutOfImpl, DiagWithDummySpan, LintPassByHand,
+    NonExistentDocKeyword, NonGlobImportTypeIrInherent, QueryInstability, QueryUntracked,
+    SpanUseEqCtxtDiag, TyQualified, TykindDiag, TykindKind, TypeIrInherentUsage,
+    UntranslatableDiag,
 };
 use crate::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintContext};
 
@@ -455,7 +456,21 @@ fn check_item(&mut self, cx: &LateContext<'_>, item: &rustc_hir::Item<'_>) {
     report_in_external_macro: true
 }
 
-declare_lint_pass!(Diagnostics => [UNTRANSLATABLE_DIAGNOSTIC, DIAGNOSTIC_OUTSIDE_OF_IMPL]);
+declare_tool_lint! {
+    /// The `diagnostic_with_dummy_span` lint detects `DUMMY_SP` being passed to functions
+    /// annotated with `#[rustc_lint_diagnostics]`, which results in diagnostics without any
+    /// source location.
+    pub rustc::DIAGNOSTIC_WITH_DUMMY_SPAN,
+    Allow,
+    "prevent diagnostics from being emitted with `DUMMY_SP`",
+    report_in_external_macro: true
+}
+
+declare_lint_pass!(Diagnostics => [
+    UNTRANSLATABLE_DIAGNOSTIC,
+    DIAGNOSTIC_OUTSIDE_OF_IMPL,
+    DIAGNOSTIC_WITH_DUMMY_SPAN,
+]);
 
 impl LateLintPass<'_> for Diagnostics {
     fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
@@ -465,12 +480,16 @@ fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
             result
         };
         // Only check function calls and method calls.
-        let (span, def_id, fn_gen_args, arg_tys_and_spans) = match expr.kind {
+        let (span, def_id, fn_gen_args, args, arg_tys_and_spans) = match expr.kind {
             ExprKind::Call(callee, args) => {
                 match cx.typeck_results().node_type(callee.hir_id).kind() {
-                    &ty::FnDef(def_id, fn_gen_args) => {
-                        (callee.span, def_id, fn_gen_args, collect_args_tys_and_spans(args, false))
-                    }
+                    &ty::FnDef(def_id, fn_gen_args) => (
+                        callee.span,
+                        def_id,
+                        fn_gen_args,
+                        args,
+                        collect_args_tys_and_spans(args, false),
+                    ),
                     _ => return, // occurs for fns passed as args
                 }
             }
@@ -479,15 +498,17 @@ fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
                 else {
                     return;
                 };
-                let mut args = collect_args_tys_and_spans(args, true);
-                args.insert(0, (cx.tcx.types.self_param, _recv.span)); // dummy inserted for `self`
-                (span, def_id, fn_gen_args, args)
+                let mut arg_tys_and_spans = collect_args_tys_and_spans(args, true);
+                // dummy inserted for `self`
+                arg_tys_and_spans.insert(0, (cx.tcx.types.self_param, _recv.span));
+                (span, def_id, fn_gen_args, args, arg_tys_and_spans)
             }
             _ => return,
         };
 
         Self::diagnostic_outside_of_impl(cx, span, expr.hir_id, def_id, fn_gen_args);
         Self::untranslatable_diagnostic(cx, def_id, &arg_tys_and_spans);
+        Self::diagnostic_with_dummy_span(cx, def_id, fn_gen_args, args);
     }
 }
 
@@ -543,6 +564,18 @@ fn untranslatable_diagnostic<'cx>(
         }
     }
 
+    // Is the callee marked with `#[rustc_lint_diagnostics]`?
+    fn is_lint_diagnostics_fn<'cx>(
+        cx: &LateContext<'cx>,
+        def_id: DefId,
+        fn_gen_args: GenericArgsRef<'cx>,
+    ) -> bool {
+        ty::Instance::try_resolve(cx.tcx, cx.param_env, def_id, fn_gen_args)
+            .ok()
+            .flatten()
+            .is_some_and(|inst| cx.tcx.has_attr(inst.def_id(), sym::rustc_lint_diagnostics))
+    }
+
     fn diagnostic_outside_of_impl<'cx>(
         cx: &LateContext<'cx>,
         span: Span,
@@ -550,16 +583,9 @@ fn diagnostic_outside_of_impl<'cx>(
         def_id: DefId,
         fn_gen_args: GenericArgsRef<'cx>,
     ) {
-        // Is the callee marked with `#[rustc_lint_diagnostics]`?
-        let Some(inst) =
-            ty::Instance::try_resolve(cx.tcx, cx.param_env, def_id, fn_gen_args).ok().flatten()
-        else {
-            return;
-        };
-        let has_attr = cx.tcx.has_attr(inst.def_id(), sym::rustc_lint_diagnostics);
-        if !has_attr {
+        if !Self::is_lint_diagnostics_fn(cx, def_id, fn_gen_args) {
             return;
-        };
+        }
 
         for (hir_id, _parent) in cx.tcx.hir().parent_iter(current_id) {
             if let Some(owner_did) = hir_id.as_owner()
@@ -593,6 +619,26 @@ fn diagnostic_outside_of_impl<'cx>(
             cx.emit_span_lint(DIAGNOSTIC_OUTSIDE_OF_IMPL, span, DiagOutOfImpl);
         }
     }
+
+    fn diagnostic_with_dummy_span<'cx>(
+        cx: &LateContext<'cx>,
+        def_id: DefId,
+        fn_gen_args: GenericArgsRef<'cx>,
+        args: &[Expr<'_>],
+    ) {
+        if !Self::is_lint_diagnostics_fn(cx, def_id, fn_gen_args) {
+            return;
+        }
+
+        for arg in args {
+            if let ExprKind::Path(ref qpath) = arg.kind
+                && let Res::Def(DefKind::Const, const_did) = cx.qpath_res(qpath, arg.hir_id)
+                && cx.tcx.is_diagnostic_item(sym::DUMMY_SP, const_did)
+            {
+                cx.emit_span_lint(DIAGNOSTIC_WITH_DUMMY_SPAN, arg.span, DiagWithDummySpan);
+            }
+        }
+    }
 }
 
 declare_tool_lint! {
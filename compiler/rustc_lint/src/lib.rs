@@ -622,6 +622,7 @@ fn register_internals(store: &mut LintStore) {
             LintId::of(EXISTING_DOC_KEYWORD),
             LintId::of(BAD_OPT_ACCESS),
             LintId::of(SPAN_USE_EQ_CTXT),
+            LintId::of(DIAGNOSTIC_WITH_DUMMY_SPAN),
         ],
     );
 }
@@ -955,6 +955,11 @@ pub(crate) struct NonExistentDocKeyword {
 #[diag(lint_diag_out_of_impl)]
 pub(crate) struct DiagOutOfImpl;
 
+#[derive(LintDiagnostic)]
+#[diag(lint_diag_with_dummy_span)]
+#[help]
+pub(crate) struct DiagWithDummySpan;
+
 #[derive(LintDiagnostic)]
 #[diag(lint_untranslatable_diag)]
 pub(crate) struct UntranslatableDiag;
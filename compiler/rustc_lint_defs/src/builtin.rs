@@ -40,11 +40,13 @@
         DEPRECATED_SAFE_2024,
         DEPRECATED_WHERE_CLAUSE_LOCATION,
         DUPLICATE_MACRO_ATTRIBUTES,
+        DYNAMIC_STD_IN_CDYLIB,
         ELIDED_LIFETIMES_IN_ASSOCIATED_CONSTANT,
         ELIDED_LIFETIMES_IN_PATHS,
         ELIDED_NAMED_LIFETIMES,
         EXPLICIT_BUILTIN_CFGS_IN_FLAGS,
         EXPORTED_PRIVATE_DEPENDENCIES,
+        EXPORTED_RUNTIME_SYMBOLS,
         FFI_UNWIND_CALLS,
         FORBIDDEN_LINT_GROUPS,
         FUNCTION_ITEM_REFERENCES,
@@ -5095,3 +5097,68 @@
         reference: "issue #124535 <https://github.com/rust-lang/rust/issues/124535>",
     };
 }
+
+declare_lint! {
+    /// The `exported_runtime_symbols` lint detects `#[export_name]` attributes whose symbol name
+    /// is also defined by the C runtime or the operating system, such as `malloc` or `memcpy`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #[export_name = "malloc"]
+    /// pub extern "C" fn my_malloc(_size: usize) -> *mut u8 {
+    ///     std::ptr::null_mut()
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// An exported symbol with the same name as a runtime function silently replaces that
+    /// function for the whole program, including for code that isn't written in Rust. For
+    /// example, exporting `malloc` also replaces the allocator used by the C runtime and by
+    /// Rust's default global allocator, which is undefined behavior unless the function
+    /// actually implements the documented contract of `malloc`.
+    ///
+    /// If replacing the runtime function is intentional, the lint can be allowed on the item.
+    pub EXPORTED_RUNTIME_SYMBOLS,
+    Deny,
+    "detects `#[export_name]` attributes that shadow well-known runtime symbols"
+}
+
+declare_lint! {
+    /// The `dynamic_std_in_cdylib` lint detects a `cdylib` that links the standard library
+    /// dynamically.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a dependency that is only available as a dylib)
+    /// #![crate_type = "cdylib"]
+    ///
+    /// extern crate dylib_dep;
+    ///
+    /// #[no_mangle]
+    /// pub extern "C" fn foo() {}
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: this `cdylib` links dynamically against `std`
+    ///   |
+    ///   = note: the Rust standard library shared object must be present wherever the library is loaded
+    ///   = help: `crate-type = "staticlib"` is usually preferred for C-compatible libraries
+    ///   = note: `#[warn(dynamic_std_in_cdylib)]` on by default
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// A `cdylib` is meant to be loaded by code that isn't written in Rust, which usually doesn't
+    /// ship the shared object of the standard library. This happens when one of the dependencies
+    /// is only available as a `dylib`. The lint doesn't fire with `-C prefer-dynamic`, since the
+    /// dynamic linkage was asked for in that case.
+    pub DYNAMIC_STD_IN_CDYLIB,
+    Warn,
+    "detects a `cdylib` that links the standard library dynamically"
+}
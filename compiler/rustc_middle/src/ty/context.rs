@@ -540,7 +540,7 @@ fn trait_may_be_implemented_via_object(self, trait_def_id: DefId) -> bool {
     }
 
     fn delay_bug(self, msg: impl ToString) -> ErrorGuaranteed {
-        self.dcx().span_delayed_bug(DUMMY_SP, msg.to_string())
+        self.dcx().delayed_bug(msg.to_string())
     }
 
     fn is_general_coroutine(self, coroutine_def_id: DefId) -> bool {
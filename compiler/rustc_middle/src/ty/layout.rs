@@ -1122,6 +1122,64 @@ fn is_transparent(this: TyAndLayout<'tcx>) -> bool {
     }
 }
 
+#[extension(pub trait TyAndLayoutExt<'tcx>)]
+impl<'tcx> TyAndLayout<'tcx> {
+    /// Returns `true` if the layout of this type may differ between 32-bit and 64-bit targets.
+    ///
+    /// This only looks at the type and the types it contains, and returns `true` if any of them
+    /// is pointer-sized (`usize`, `isize`, raw pointers, references and function pointers), an
+    /// integer or float wider than 32 bits, a `#[repr(C)]` enum without an explicit integer
+    /// type, or an enum whose tag is one of the former (e.g. `#[repr(usize)]` or `#[repr(u64)]`).
+    /// Differences that only exist on 16-bit targets, such as the alignment of `u16` on AVR, are
+    /// not taken into account.
+    fn is_platform_dependent<C>(&self, cx: &C) -> bool
+    where
+        C: HasTyCtxt<'tcx> + HasParamEnv<'tcx>,
+    {
+        match *self.ty.kind() {
+            ty::Int(ty::IntTy::Isize)
+            | ty::Uint(ty::UintTy::Usize)
+            | ty::RawPtr(..)
+            | ty::Ref(..)
+            | ty::FnPtr(..) => return true,
+            ty::Int(_) | ty::Uint(_) | ty::Float(_) => return self.size.bytes() > 4,
+            ty::Adt(def, _) if def.is_enum() && def.repr().c() && def.repr().int.is_none() => {
+                return true;
+            }
+            // The tag of a `#[repr(usize)]` enum only has 32 bits on 32-bit targets, which the
+            // check of the tag below doesn't catch there.
+            ty::Adt(def, _)
+                if def.is_enum() && matches!(def.repr().int, Some(IntegerType::Pointer(_))) =>
+            {
+                return true;
+            }
+            _ => {}
+        }
+
+        let any_field_is_platform_dependent = |layout: TyAndLayout<'tcx>| match layout.fields {
+            FieldsShape::Primitive => false,
+            // All elements of an array share the same layout.
+            FieldsShape::Array { .. } => layout.field(cx, 0).is_platform_dependent(cx),
+            FieldsShape::Union(_) | FieldsShape::Arbitrary { .. } => {
+                (0..layout.fields.count()).any(|i| layout.field(cx, i).is_platform_dependent(cx))
+            }
+        };
+
+        match &self.variants {
+            Variants::Single { .. } => any_field_is_platform_dependent(*self),
+            // The tag is not a field of any of the variants, so it has to be checked separately.
+            Variants::Multiple { tag, variants, .. } => {
+                let tag = tag.primitive();
+                matches!(tag, Primitive::Pointer(_))
+                    || tag.size(cx).bytes() > 4
+                    || variants.indices().any(|variant| {
+                        any_field_is_platform_dependent(self.for_variant(cx, variant))
+                    })
+            }
+        }
+    }
+}
+
 /// Calculates whether a function's ABI can unwind or not.
 ///
 /// This takes two primary parameters:
@@ -291,6 +291,10 @@ passes_export_name =
     attribute should be applied to a free function, impl method or static
     .label = not a free function, impl method or static
 
+passes_exported_runtime_symbol =
+    `#[export_name]` shadows the runtime symbol `{$name}`
+    .note = all calls to `{$name}` in the program, including from the runtime, will use this item
+
 passes_extern_main =
     the `main` function cannot be declared in an `extern` block
 
@@ -407,6 +411,8 @@ passes_layout_invalid_attribute =
     `#[rustc_layout]` can only be applied to `struct`/`enum`/`union` declarations and type aliases
 passes_layout_of =
     layout_of({$normalized_ty}) = {$ty_layout}
+passes_layout_platform_dependent =
+    platform_dependent: {$platform_dependent}
 passes_layout_size =
     size: {$size}
 
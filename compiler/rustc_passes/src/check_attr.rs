@@ -28,8 +28,8 @@
 use rustc_middle::ty::{self, TyCtxt};
 use rustc_middle::{bug, span_bug};
 use rustc_session::lint::builtin::{
-    CONFLICTING_REPR_HINTS, INVALID_DOC_ATTRIBUTES, INVALID_MACRO_EXPORT_ARGUMENTS,
-    UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES, UNUSED_ATTRIBUTES,
+    CONFLICTING_REPR_HINTS, EXPORTED_RUNTIME_SYMBOLS, INVALID_DOC_ATTRIBUTES,
+    INVALID_MACRO_EXPORT_ARGUMENTS, UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES, UNUSED_ATTRIBUTES,
 };
 use rustc_session::parse::feature_err;
 use rustc_span::symbol::{kw, sym, Symbol};
@@ -1503,11 +1503,14 @@ fn is_impl_item(&self, hir_id: HirId) -> bool {
         matches!(self.tcx.hir_node(hir_id), hir::Node::ImplItem(..))
     }
 
-    /// Checks if `#[export_name]` is applied to a function or static.
+    /// Checks if `#[export_name]` is applied to a function or static, and that it doesn't shadow
+    /// a runtime symbol.
     fn check_export_name(&self, hir_id: HirId, attr: &Attribute, span: Span, target: Target) {
         match target {
-            Target::Static | Target::Fn => {}
-            Target::Method(..) if self.is_impl_item(hir_id) => {}
+            Target::Static | Target::Fn => self.check_export_name_value(hir_id, attr),
+            Target::Method(..) if self.is_impl_item(hir_id) => {
+                self.check_export_name_value(hir_id, attr)
+            }
             // FIXME(#80564): We permit struct fields, match arms and macro defs to have an
             // `#[export_name]` attribute with just a lint, because we previously
             // erroneously allowed it and some crates used it accidentally, to be compatible
@@ -1521,6 +1524,25 @@ fn check_export_name(&self, hir_id: HirId, attr: &Attribute, span: Span, target:
         }
     }
 
+    fn check_export_name_value(&self, hir_id: HirId, attr: &Attribute) {
+        /// Symbols defined by the C runtime or the OS that are used implicitly by other code.
+        const RUNTIME_SYMBOLS: &[&str] = &[
+            "abort", "calloc", "exit", "free", "main", "malloc", "memcmp", "memcpy", "memmove",
+            "memset", "realloc", "strlen",
+        ];
+
+        if let Some(name) = attr.value_str()
+            && RUNTIME_SYMBOLS.contains(&name.as_str())
+        {
+            self.tcx.emit_node_span_lint(
+                EXPORTED_RUNTIME_SYMBOLS,
+                hir_id,
+                attr.span,
+                errors::ExportedRuntimeSymbol { name },
+            );
+        }
+    }
+
     fn check_rustc_layout_scalar_valid_range(&self, attr: &Attribute, span: Span, target: Target) {
         if target != Target::Struct {
             self.dcx().emit_err(errors::RustcLayoutScalarValidRangeNotStruct {
@@ -433,6 +433,13 @@ pub(crate) struct ExportName {
     pub span: Span,
 }
 
+#[derive(LintDiagnostic)]
+#[diag(passes_exported_runtime_symbol)]
+#[note]
+pub(crate) struct ExportedRuntimeSymbol {
+    pub name: Symbol,
+}
+
 #[derive(Diagnostic)]
 #[diag(passes_rustc_layout_scalar_valid_range_not_struct)]
 pub(crate) struct RustcLayoutScalarValidRangeNotStruct {
@@ -935,6 +942,14 @@ pub(crate) struct LayoutAlign {
     pub align: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(passes_layout_platform_dependent)]
+pub(crate) struct LayoutPlatformDependent {
+    #[primary_span]
+    pub span: Span,
+    pub platform_dependent: bool,
+}
+
 #[derive(Diagnostic)]
 #[diag(passes_layout_size)]
 pub(crate) struct LayoutSize {
@@ -1280,6 +1295,8 @@ impl<'a, G: EmissionGuarantee> Diagnostic<'a, G> for NoMainErr {
     #[track_caller]
     fn into_diag(self, dcx: DiagCtxtHandle<'a>, level: Level) -> Diag<'a, G> {
         let mut diag = Diag::new(dcx, level, fluent::passes_no_main_function);
+        // There's no `main` to point at.
+        #[allow(rustc::diagnostic_with_dummy_span)]
         diag.span(DUMMY_SP);
         diag.code(E0601);
         diag.arg("crate_name", self.crate_name);
@@ -2,7 +2,9 @@
 use rustc_hir::def::DefKind;
 use rustc_hir::def_id::LocalDefId;
 use rustc_middle::span_bug;
-use rustc_middle::ty::layout::{HasParamEnv, HasTyCtxt, LayoutError, LayoutOfHelpers, TyAndLayout};
+use rustc_middle::ty::layout::{
+    HasParamEnv, HasTyCtxt, LayoutError, LayoutOfHelpers, TyAndLayout, TyAndLayoutExt,
+};
 use rustc_middle::ty::{self, ParamEnv, Ty, TyCtxt};
 use rustc_span::source_map::Spanned;
 use rustc_span::symbol::sym;
@@ -14,7 +16,7 @@
 
 use crate::errors::{
     LayoutAbi, LayoutAlign, LayoutHomogeneousAggregate, LayoutInvalidAttribute, LayoutOf,
-    LayoutSize, UnrecognizedField,
+    LayoutPlatformDependent, LayoutSize, UnrecognizedField,
 };
 
 pub fn test_layout(tcx: TyCtxt<'_>) {
@@ -107,6 +109,14 @@ fn dump_layout_of(tcx: TyCtxt<'_>, item_def_id: LocalDefId, attr: &Attribute) {
                         });
                     }
 
+                    sym::platform_dependent => {
+                        tcx.dcx().emit_err(LayoutPlatformDependent {
+                            span,
+                            platform_dependent: ty_layout
+                                .is_platform_dependent(&UnwrapLayoutCx { tcx, param_env }),
+                        });
+                    }
+
                     sym::debug => {
                         let normalized_ty = format!(
                             "{}",
@@ -1331,6 +1331,19 @@ fn fallback(span: Span, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// Returns the source code `span` points to, using the global `SourceMap`.
+///
+/// Like `<Span as Debug>::fmt`, this is meant for `Debug` implementations which have no access to
+/// the `Session`. Returns `None` if there is no source map or the snippet is not available.
+pub fn debug_snippet(span: Span) -> Option<String> {
+    if !SESSION_GLOBALS.is_set() {
+        return None;
+    }
+    with_session_globals(|session_globals| {
+        session_globals.source_map.as_ref()?.span_to_snippet(span).ok()
+    })
+}
+
 impl fmt::Debug for SpanData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Debug::fmt(&self.span(), f)
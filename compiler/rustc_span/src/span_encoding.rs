@@ -240,6 +240,7 @@ macro_rules! match_span_kind {
 const CTXT_INTERNED_MARKER: u16 = 0b1111_1111_1111_1111;
 
 /// The dummy span has zero position, length, and context, and no parent.
+#[cfg_attr(not(test), rustc_diagnostic_item = "DUMMY_SP")]
 pub const DUMMY_SP: Span =
     Span { lo_or_index: 0, len_with_tag_or_marker: 0, ctxt_or_parent_or_marker: 0 };
 
@@ -182,6 +182,7 @@
         Continue,
         Copy,
         Cow,
+        DUMMY_SP,
         Debug,
         DebugStruct,
         Decodable,
@@ -1418,6 +1419,7 @@
         pic,
         pie,
         pin,
+        platform_dependent,
         platform_intrinsics,
         plugin,
         plugin_registrar,
@@ -114,7 +114,11 @@ pub fn is_const_evaluatable<'tcx>(
                         param_env,
                     ) =>
             {
-                tcx.dcx()
+                // The suggestion uses `DUMMY_SP` so that it is rendered at the very start of the
+                // crate root, where crate-level attributes go.
+                #[allow(rustc::diagnostic_with_dummy_span)]
+                let diag = tcx
+                    .dcx()
                     .struct_span_fatal(
                         // Slightly better span than just using `span` alone
                         if span == DUMMY_SP { tcx.def_span(uv.def) } else { span },
@@ -126,8 +130,8 @@ pub fn is_const_evaluatable<'tcx>(
                         "consider enabling this feature",
                         "#![feature(generic_const_exprs)]\n",
                         rustc_errors::Applicability::MaybeIncorrect,
-                    )
-                    .emit()
+                    );
+                diag.emit()
             }
 
             Err(ErrorHandled::TooGeneric(_)) => {
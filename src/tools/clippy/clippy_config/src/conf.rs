@@ -573,6 +573,10 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// exported visibility, or whether they are marked as "pub".
     #[lints(pub_underscore_fields)]
     pub_underscore_fields_behavior: PubUnderscoreFieldsBehaviour = PubUnderscoreFieldsBehaviour::PubliclyExported,
+    /// Whether every entry of `disallowed-macros`, `disallowed-methods` and `disallowed-types` is
+    /// required to have a `reason`.
+    #[lints(disallowed_macros, disallowed_methods, disallowed_types)]
+    restriction_reason_required: bool = false,
     /// Whether to lint only if it's multiline.
     #[lints(semicolon_inside_block)]
     semicolon_inside_block_ignore_singleline: bool = false,
@@ -715,6 +719,9 @@ fn deserialize(file: &SourceFile) -> TryConf {
                     .doc_valid_idents
                     .extend(DEFAULT_DOC_VALID_IDENTS.iter().map(ToString::to_string));
             }
+            if conf.conf.restriction_reason_required {
+                check_disallowed_reasons(file, &mut conf.errors);
+            }
 
             conf
         },
@@ -722,6 +729,42 @@ fn deserialize(file: &SourceFile) -> TryConf {
     }
 }
 
+/// The `disallowed-*` entries together with their spans, which aren't kept in [`Conf`].
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct SpannedDisallowedPaths {
+    #[serde(default)]
+    disallowed_macros: Vec<toml::Spanned<DisallowedPath>>,
+    #[serde(default)]
+    disallowed_methods: Vec<toml::Spanned<DisallowedPath>>,
+    #[serde(default)]
+    disallowed_types: Vec<toml::Spanned<DisallowedPath>>,
+}
+
+fn check_disallowed_reasons(file: &SourceFile, errors: &mut Vec<ConfError>) {
+    // An invalid entry has already been reported while deserializing the `Conf`
+    let Ok(paths) = toml::from_str::<SpannedDisallowedPaths>(file.src.as_ref().unwrap()) else {
+        return;
+    };
+    for (key, disallowed) in [
+        ("disallowed-macros", paths.disallowed_macros),
+        ("disallowed-methods", paths.disallowed_methods),
+        ("disallowed-types", paths.disallowed_types),
+    ] {
+        for path in disallowed.iter().filter(|path| path.get_ref().reason().is_none()) {
+            errors.push(ConfError::spanned(
+                file,
+                format!(
+                    "the `{key}` entry `{}` has no `reason`, which is required by `restriction-reason-required`",
+                    path.get_ref().path()
+                ),
+                None,
+                path.span(),
+            ));
+        }
+    }
+}
+
 fn extend_vec_if_indicator_present(vec: &mut Vec<String>, default: &[&str]) {
     if vec.contains(&"..".to_string()) {
         vec.extend(default.iter().map(ToString::to_string));
@@ -12,7 +12,11 @@ pub struct Rename {
 #[serde(untagged)]
 pub enum DisallowedPath {
     Simple(String),
-    WithReason { path: String, reason: Option<String> },
+    WithReason {
+        path: String,
+        reason: Option<String>,
+        severity: Option<LintLevel>,
+    },
 }
 
 impl DisallowedPath {
@@ -28,6 +32,21 @@ pub fn reason(&self) -> Option<&str> {
             Self::Simple(_) => None,
         }
     }
+
+    pub fn severity(&self) -> Option<LintLevel> {
+        match &self {
+            Self::WithReason { severity, .. } => *severity,
+            Self::Simple(_) => None,
+        }
+    }
+}
+
+/// The level a diagnostic is emitted at, overriding the level of the lint that emits it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Warn,
+    Deny,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
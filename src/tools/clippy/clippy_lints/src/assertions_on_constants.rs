@@ -1,11 +1,11 @@
 use clippy_utils::consts::{ConstEvalCtxt, Constant};
 use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::is_inside_always_const_context;
-use clippy_utils::macros::{find_assert_args, root_macro_call_first_node, PanicExpn};
+use clippy_utils::higher::{AssertArgs, AssertInfo, AssertKind};
+use clippy_utils::macros::PanicExpn;
+use clippy_utils::{higher, is_inside_always_const_context};
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::declare_lint_pass;
-use rustc_span::sym;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -32,15 +32,14 @@
 
 impl<'tcx> LateLintPass<'tcx> for AssertionsOnConstants {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
-        let Some(macro_call) = root_macro_call_first_node(cx, e) else {
-            return;
-        };
-        let is_debug = match cx.tcx.get_diagnostic_name(macro_call.def_id) {
-            Some(sym::debug_assert_macro) => true,
-            Some(sym::assert_macro) => false,
-            _ => return,
-        };
-        let Some((condition, panic_expn)) = find_assert_args(cx, e, macro_call.expn) else {
+        let Some(AssertInfo {
+            macro_call,
+            kind: AssertKind::Assert,
+            is_debug,
+            args: AssertArgs::Condition(condition),
+            panic_expn,
+        }) = higher::parse_assert(cx, e)
+        else {
             return;
         };
         let Some(Constant::Bool(val)) = ConstEvalCtxt::new(cx).eval(condition) else {
@@ -1,9 +1,10 @@
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::macros::{find_assert_args, root_macro_call_first_node, PanicExpn};
+use clippy_utils::higher::{AssertArgs, AssertInfo, AssertKind};
+use clippy_utils::macros::PanicExpn;
 use clippy_utils::source::snippet_with_context;
 use clippy_utils::ty::{has_debug_impl, is_copy, is_type_diagnostic_item};
 use clippy_utils::usage::local_used_after_expr;
-use clippy_utils::{is_expr_final_block_expr, path_res};
+use clippy_utils::{higher, is_expr_final_block_expr, path_res};
 use rustc_errors::Applicability;
 use rustc_hir::def::Res;
 use rustc_hir::{Expr, ExprKind};
@@ -49,10 +50,13 @@
 
 impl<'tcx> LateLintPass<'tcx> for AssertionsOnResultStates {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
-        if let Some(macro_call) = root_macro_call_first_node(cx, e)
-            && matches!(cx.tcx.get_diagnostic_name(macro_call.def_id), Some(sym::assert_macro))
-            && let Some((condition, panic_expn)) = find_assert_args(cx, e, macro_call.expn)
-            && matches!(panic_expn, PanicExpn::Empty)
+        if let Some(AssertInfo {
+            macro_call,
+            kind: AssertKind::Assert,
+            is_debug: false,
+            args: AssertArgs::Condition(condition),
+            panic_expn: PanicExpn::Empty,
+        }) = higher::parse_assert(cx, e)
             && let ExprKind::MethodCall(method_segment, recv, [], _) = condition.kind
             && let result_type_with_refs = cx.typeck_results().expr_ty(recv)
             && let result_type = result_type_with_refs.peel_refs()
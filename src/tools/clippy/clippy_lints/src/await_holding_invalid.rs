@@ -1,3 +1,4 @@
+use clippy_config::types::DisallowedPath;
 use clippy_config::Conf;
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::{create_disallowed_map, match_def_path, paths};
@@ -173,7 +174,7 @@
 impl_lint_pass!(AwaitHolding => [AWAIT_HOLDING_LOCK, AWAIT_HOLDING_REFCELL_REF, AWAIT_HOLDING_INVALID_TYPE]);
 
 pub struct AwaitHolding {
-    def_ids: DefIdMap<(&'static str, Option<&'static str>)>,
+    def_ids: DefIdMap<&'static DisallowedPath>,
 }
 
 impl AwaitHolding {
@@ -246,8 +247,8 @@ fn check_interior_types(&self, cx: &LateContext<'_>, coroutine: &CoroutineLayout
                             );
                         },
                     );
-                } else if let Some(&(path, reason)) = self.def_ids.get(&adt.did()) {
-                    emit_invalid_type(cx, ty_cause.source_info.span, path, reason);
+                } else if let Some(&disallowed) = self.def_ids.get(&adt.did()) {
+                    emit_invalid_type(cx, ty_cause.source_info.span, disallowed.path(), disallowed.reason());
                 }
             }
         }
@@ -1,6 +1,7 @@
+use clippy_config::types::DisallowedPath;
 use clippy_config::Conf;
 use clippy_utils::create_disallowed_map;
-use clippy_utils::diagnostics::{span_lint_and_then, span_lint_hir_and_then};
+use clippy_utils::diagnostics::{span_lint_hir_and_then, span_lint_hir_with_level_and_then};
 use clippy_utils::macros::macro_backtrace;
 use rustc_ast::Attribute;
 use rustc_data_structures::fx::FxHashSet;
@@ -37,6 +38,10 @@
     ///     # When using an inline table, can add a `reason` for why the macro
     ///     # is disallowed.
     ///     { path = "serde::Serialize", reason = "no serializing" },
+    ///     # Can also set a `severity` of "warn" or "deny" to emit a warning or
+    ///     # an error for this macro instead of using the level of the lint.
+    ///     # The lint can still be allowed as usual.
+    ///     { path = "std::dbg", severity = "deny" },
     /// ]
     /// ```
     /// ```no_run
@@ -59,7 +64,7 @@
 }
 
 pub struct DisallowedMacros {
-    disallowed: DefIdMap<(&'static str, Option<&'static str>)>,
+    disallowed: DefIdMap<&'static DisallowedPath>,
     seen: FxHashSet<ExpnId>,
     // Track the most recently seen node that can have a `derive` attribute.
     // Needed to use the correct lint level.
@@ -85,26 +90,24 @@ fn check(&mut self, cx: &LateContext<'_>, span: Span, derive_src: Option<OwnerId
                 return;
             }
 
-            if let Some(&(path, reason)) = self.disallowed.get(&mac.def_id) {
-                let msg = format!("use of a disallowed macro `{path}`");
+            if let Some(&disallowed) = self.disallowed.get(&mac.def_id) {
+                let msg = format!("use of a disallowed macro `{}`", disallowed.path());
                 let add_note = |diag: &mut Diag<'_, _>| {
-                    if let Some(reason) = reason {
+                    if let Some(reason) = disallowed.reason() {
                         diag.note(reason);
                     }
                 };
-                if matches!(mac.kind, MacroKind::Derive)
-                    && let Some(derive_src) = derive_src
-                {
-                    span_lint_hir_and_then(
-                        cx,
-                        DISALLOWED_MACROS,
-                        cx.tcx.local_def_id_to_hir_id(derive_src.def_id),
-                        mac.span,
-                        msg,
-                        add_note,
-                    );
+                // Derives are checked on the attribute, use the level of the item they're applied to.
+                let hir_id = match derive_src {
+                    Some(derive_src) if matches!(mac.kind, MacroKind::Derive) => {
+                        cx.tcx.local_def_id_to_hir_id(derive_src.def_id)
+                    },
+                    _ => cx.last_node_with_lint_attrs,
+                };
+                if let Some(level) = disallowed.severity() {
+                    span_lint_hir_with_level_and_then(cx, DISALLOWED_MACROS, level, hir_id, mac.span, msg, add_note);
                 } else {
-                    span_lint_and_then(cx, DISALLOWED_MACROS, mac.span, msg, add_note);
+                    span_lint_hir_and_then(cx, DISALLOWED_MACROS, hir_id, mac.span, msg, add_note);
                 }
             }
         }
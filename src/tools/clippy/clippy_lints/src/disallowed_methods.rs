@@ -1,6 +1,8 @@
+use clippy_config::types::DisallowedPath;
 use clippy_config::Conf;
 use clippy_utils::create_disallowed_map;
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::{span_lint_and_then, span_lint_hir_with_level_and_then};
+use rustc_errors::Diag;
 use rustc_hir::def::{CtorKind, DefKind, Res};
 use rustc_hir::def_id::DefIdMap;
 use rustc_hir::{Expr, ExprKind};
@@ -31,9 +33,17 @@
     ///     # When using an inline table, can add a `reason` for why the method
     ///     # is disallowed.
     ///     { path = "std::vec::Vec::leak", reason = "no leaking memory" },
+    ///     # Can also set a `severity` of "warn" or "deny" to emit a warning or
+    ///     # an error for this method instead of using the level of the lint.
+    ///     # A `severity` doesn't override `#[allow]` or `#[expect]`, which still
+    ///     # silence the lint as usual.
+    ///     { path = "std::process::exit", severity = "deny" },
     /// ]
     /// ```
     ///
+    /// Trait methods of operators such as `std::ops::Add::add` can also be disallowed,
+    /// in which case overloaded uses of the operator (`a + b`) are linted as well.
+    ///
     /// ```rust,ignore
     /// // Example code where clippy issues a warning
     /// let xs = vec![1, 2, 3, 4];
@@ -58,7 +68,7 @@
 }
 
 pub struct DisallowedMethods {
-    disallowed: DefIdMap<(&'static str, Option<&'static str>)>,
+    disallowed: DefIdMap<&'static DisallowedPath>,
 }
 
 impl DisallowedMethods {
@@ -83,20 +93,30 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             ExprKind::MethodCall(name, ..) if let Some(id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) => {
                 (id, name.ident.span)
             },
+            // Overloaded operators desugar to calls of their trait methods, e.g. `a + b` to `Add::add(a, b)`.
+            // Operators on primitives are builtin and have no type dependent def.
+            ExprKind::Binary(op, ..) | ExprKind::AssignOp(op, ..)
+                if let Some(id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) =>
+            {
+                (id, op.span)
+            },
+            ExprKind::Unary(..) if let Some(id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) => {
+                (id, expr.span)
+            },
             _ => return,
         };
-        if let Some(&(path, reason)) = self.disallowed.get(&id) {
-            span_lint_and_then(
-                cx,
-                DISALLOWED_METHODS,
-                span,
-                format!("use of a disallowed method `{path}`"),
-                |diag| {
-                    if let Some(reason) = reason {
-                        diag.note(reason);
-                    }
-                },
-            );
+        if let Some(&disallowed) = self.disallowed.get(&id) {
+            let msg = format!("use of a disallowed method `{}`", disallowed.path());
+            let add_note = |diag: &mut Diag<'_, _>| {
+                if let Some(reason) = disallowed.reason() {
+                    diag.note(reason);
+                }
+            };
+            if let Some(level) = disallowed.severity() {
+                span_lint_hir_with_level_and_then(cx, DISALLOWED_METHODS, level, expr.hir_id, span, msg, add_note);
+            } else {
+                span_lint_and_then(cx, DISALLOWED_METHODS, span, msg, add_note);
+            }
         }
     }
 }
@@ -0,0 +1,69 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::is_trait_impl_item;
+use rustc_ast::token::CommentKind;
+use rustc_ast::{AttrKind, AttrStyle, Attribute};
+use rustc_errors::Applicability;
+use rustc_hir::{ItemKind, Node};
+use rustc_lint::{LateContext, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_span::Span;
+
+use super::DOC_COMMENTS_ON_PRIVATE_ITEMS;
+
+pub fn check(cx: &LateContext<'_>, attrs: &[Attribute]) {
+    let def_id = match cx.tcx.hir_node(cx.last_node_with_lint_attrs) {
+        // The docs of impl blocks are shown on the page of the implementing type
+        Node::Item(item) if !matches!(item.kind, ItemKind::Impl(_)) => item.owner_id.def_id,
+        Node::TraitItem(item) => item.owner_id.def_id,
+        // The docs of trait impl items are shown on public types even if the trait is private
+        Node::ImplItem(item) if !is_trait_impl_item(cx, item.hir_id()) => item.owner_id.def_id,
+        Node::ForeignItem(item) => item.owner_id.def_id,
+        Node::Field(field) => field.def_id,
+        Node::Variant(variant) => variant.def_id,
+        _ => return,
+    };
+    if cx.effective_visibilities.is_exported(def_id) {
+        return;
+    }
+
+    let replacements = collect_comment_replacements(cx, attrs);
+    if let Some((&(lo_span, _), &(hi_span, _))) = replacements.first().zip(replacements.last()) {
+        span_lint_and_then(
+            cx,
+            DOC_COMMENTS_ON_PRIVATE_ITEMS,
+            lo_span.to(hi_span),
+            "doc comment on an item that is not publicly visible",
+            |diag| {
+                diag.note("it is only part of the documentation when using `--document-private-items`");
+                diag.multipart_suggestion(
+                    "if it is not meant to be documentation, use a regular comment",
+                    replacements,
+                    Applicability::MaybeIncorrect,
+                );
+            },
+        );
+    }
+}
+
+fn collect_comment_replacements(cx: &LateContext<'_>, attrs: &[Attribute]) -> Vec<(Span, String)> {
+    attrs
+        .iter()
+        .filter_map(|attr| {
+            if let AttrKind::DocComment(com_kind, sym) = attr.kind
+                && let AttrStyle::Outer = attr.style
+                && !in_external_macro(cx.sess(), attr.span)
+                && let com = sym.as_str()
+                // `///!` is linted by `suspicious_doc_comments`, and `//!` would be an inner doc comment
+                && !com.starts_with('!')
+            {
+                let sugg = match com_kind {
+                    CommentKind::Line => format!("//{com}"),
+                    CommentKind::Block => format!("/*{com}*/"),
+                };
+                Some((attr.span, sugg))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
@@ -7,7 +7,7 @@
 use clippy_utils::macros::{is_panic, root_macro_call_first_node};
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::visitors::Visitable;
-use clippy_utils::{is_entrypoint_fn, is_trait_impl_item, method_chain_args};
+use clippy_utils::{is_entrypoint_fn, is_in_test_function, is_trait_impl_item, method_chain_args};
 use pulldown_cmark::Event::{
     Code, DisplayMath, End, FootnoteReference, HardBreak, Html, InlineHtml, InlineMath, Rule, SoftBreak, Start,
     TaskListMarker, Text,
@@ -17,7 +17,7 @@
 use rustc_ast::ast::Attribute;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir::intravisit::{self, Visitor};
-use rustc_hir::{AnonConst, Expr, ImplItemKind, ItemKind, Node, Safety, TraitItemKind};
+use rustc_hir::{AnonConst, Expr, ExprKind, ImplItemKind, ItemKind, Node, Safety, TraitItemKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::nested_filter;
 use rustc_middle::lint::in_external_macro;
@@ -32,6 +32,7 @@
 use std::ops::Range;
 use url::Url;
 
+mod doc_comments_on_private_items;
 mod link_with_quotes;
 mod markdown;
 mod missing_headers;
@@ -149,6 +150,11 @@
     /// Checks the doc comments of publicly visible functions that
     /// may panic and warns if there is no `# Panics` section.
     ///
+    /// A function is considered to possibly panic if its body contains a
+    /// `panic!` or `assert!` family macro call, an `unwrap` or `expect` call on an
+    /// `Option` or `Result`, or indexes into an array, slice, `str`, `Vec` or `VecDeque`.
+    /// `#[test]` functions are not checked.
+    ///
     /// ### Why is this bad?
     /// Documenting the scenarios in which panicking occurs
     /// can help callers who do not want to panic to avoid those situations.
@@ -455,6 +461,35 @@
     "ensure that the first line of a documentation paragraph isn't too long"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for doc comments (`///` or `/** */`) on items that are not publicly visible.
+    ///
+    /// ### Why restrict this?
+    /// The documentation of private items is only generated when passing
+    /// `--document-private-items` to rustdoc. If that is never done, such comments are only
+    /// read in the source code and can just as well be regular comments.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// /// Adds one to the value.
+    /// fn add_one(x: u32) -> u32 {
+    ///     x + 1
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// // Adds one to the value.
+    /// fn add_one(x: u32) -> u32 {
+    ///     x + 1
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub DOC_COMMENTS_ON_PRIVATE_ITEMS,
+    restriction,
+    "doc comments on items that are not publicly visible"
+}
+
 #[derive(Clone)]
 pub struct Documentation {
     valid_idents: FxHashSet<String>,
@@ -483,10 +518,13 @@ pub fn new(conf: &'static Conf) -> Self {
     EMPTY_DOCS,
     DOC_LAZY_CONTINUATION,
     TOO_LONG_FIRST_DOC_PARAGRAPH,
+    DOC_COMMENTS_ON_PRIVATE_ITEMS,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Documentation {
     fn check_attributes(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute]) {
+        doc_comments_on_private_items::check(cx, attrs);
+
         let Some(headers) = check_attrs(cx, &self.valid_idents, attrs) else {
             return;
         };
@@ -503,6 +541,7 @@ fn check_attributes(&mut self, cx: &LateContext<'tcx>, attrs: &'tcx [Attribute])
                 match item.kind {
                     ItemKind::Fn(sig, _, body_id) => {
                         if !(is_entrypoint_fn(cx, item.owner_id.to_def_id())
+                            || is_in_test_function(cx.tcx, item.hir_id())
                             || in_external_macro(cx.tcx.sess, item.span))
                         {
                             let body = cx.tcx.hir().body(body_id);
@@ -925,6 +964,19 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
             }
         }
 
+        // check for indexing, which panics when the index is out of bounds
+        if let ExprKind::Index(base, _, _) = expr.kind {
+            let base_ty = self.typeck_results.expr_ty(base).peel_refs();
+            if base_ty.is_array_slice()
+                || base_ty.is_str()
+                || is_type_diagnostic_item(self.cx, base_ty, sym::Vec)
+                || is_type_diagnostic_item(self.cx, base_ty, sym::VecDeque)
+            {
+                self.is_const = self.cx.tcx.hir().is_inside_const_context(expr.hir_id);
+                self.panic_span = Some(expr.span);
+            }
+        }
+
         // and check sub-expressions
         intravisit::walk_expr(self, expr);
     }
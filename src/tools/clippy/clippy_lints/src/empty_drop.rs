@@ -1,5 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::peel_blocks;
+use clippy_utils::source::SpanRangeExt;
 use rustc_errors::Applicability;
 use rustc_hir::{Body, ExprKind, Impl, ImplItemKind, Item, ItemKind, Node};
 use rustc_lint::{LateContext, LateLintPass};
@@ -13,6 +14,8 @@
     /// Empty `Drop` implementations have no effect when dropping an instance of the type. They are
     /// most likely useless. However, an empty `Drop` implementation prevents a type from being
     /// destructured, which might be the intention behind adding the implementation as a marker.
+    /// Implementations with an `// Intentionally empty` comment in their body are assumed to be
+    /// such markers and are not linted.
     ///
     /// ### Example
     /// ```no_run
@@ -26,6 +29,16 @@
     /// ```no_run
     /// struct S;
     /// ```
+    /// Or, if the implementation is intentional:
+    /// ```no_run
+    /// struct S;
+    ///
+    /// impl Drop for S {
+    ///     fn drop(&mut self) {
+    ///         // Intentionally empty, `S` must not be destructured
+    ///     }
+    /// }
+    /// ```
     #[clippy::version = "1.62.0"]
     pub EMPTY_DROP,
     restriction,
@@ -44,11 +57,14 @@ fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
             && let impl_item_hir = child.id.hir_id()
             && let Node::ImplItem(impl_item) = cx.tcx.hir_node(impl_item_hir)
             && let ImplItemKind::Fn(_, b) = &impl_item.kind
-            && let Body { value: func_expr, .. } = cx.tcx.hir().body(*b)
-            && let func_expr = peel_blocks(func_expr)
+            && let Body { value: body_expr, .. } = cx.tcx.hir().body(*b)
+            && let func_expr = peel_blocks(body_expr)
             && let ExprKind::Block(block, _) = func_expr.kind
             && block.stmts.is_empty()
             && block.expr.is_none()
+            && !body_expr
+                .span
+                .check_source_text(cx, |src| src.to_ascii_lowercase().contains("intentionally empty"))
         {
             span_lint_and_then(cx, EMPTY_DROP, item.span, "empty drop implementation", |diag| {
                 diag.span_suggestion_hidden(
@@ -57,6 +73,7 @@ fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
                     String::new(),
                     Applicability::MaybeIncorrect,
                 );
+                diag.help("if the implementation is intentional, add an `// Intentionally empty` comment to its body");
             });
         }
     }
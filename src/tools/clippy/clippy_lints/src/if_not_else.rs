@@ -1,6 +1,8 @@
 use clippy_utils::consts::{ConstEvalCtxt, Constant};
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::is_else_clause;
+use clippy_utils::source::{snippet_with_applicability, snippet_with_context};
+use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::declare_lint_pass;
@@ -10,6 +12,9 @@
     /// Checks for usage of `!` or `!=` in an if condition with an
     /// else branch.
     ///
+    /// A negated `&&` or `||` (e.g. `!(a && b)`) is not linted, as removing the `!` would
+    /// require applying De Morgan's laws.
+    ///
     /// ### Why is this bad?
     /// Negations reduce the readability of statements.
     ///
@@ -52,22 +57,35 @@ fn is_zero_const(expr: &Expr<'_>, cx: &LateContext<'_>) -> bool {
     false
 }
 
+/// Checks for `&&` and `||`, whose negation can't be removed without applying De Morgan's laws.
+fn is_lazy_binop(expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Binary(op, ..) if op.node.is_lazy())
+}
+
 impl LateLintPass<'_> for IfNotElse {
     fn check_expr(&mut self, cx: &LateContext<'_>, e: &Expr<'_>) {
-        if let ExprKind::If(cond, _, Some(els)) = e.kind
+        if let ExprKind::If(cond, then, Some(els)) = e.kind
             && let ExprKind::DropTemps(cond) = cond.kind
             && let ExprKind::Block(..) = els.kind
         {
-            let (msg, help) = match cond.kind {
-                ExprKind::Unary(UnOp::Not, _) => (
+            let ctxt = e.span.ctxt();
+            let mut app = Applicability::MachineApplicable;
+            let (msg, cond) = match cond.kind {
+                ExprKind::Unary(UnOp::Not, inner) if !is_lazy_binop(inner) => (
                     "unnecessary boolean `not` operation",
-                    "remove the `!` and swap the blocks of the `if`/`else`",
+                    snippet_with_context(cx, inner.span, ctxt, "..", &mut app)
+                        .0
+                        .into_owned(),
                 ),
                 // Don't lint on `… != 0`, as these are likely to be bit tests.
                 // For example, `if foo & 0x0F00 != 0 { … } else { … }` is already in the "proper" order.
-                ExprKind::Binary(op, _, rhs) if op.node == BinOpKind::Ne && !is_zero_const(rhs, cx) => (
+                ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::Ne && !is_zero_const(rhs, cx) => (
                     "unnecessary `!=` operation",
-                    "change to `==` and swap the blocks of the `if`/`else`",
+                    format!(
+                        "{} == {}",
+                        snippet_with_context(cx, lhs.span, ctxt, "..", &mut app).0,
+                        snippet_with_context(cx, rhs.span, ctxt, "..", &mut app).0,
+                    ),
                 ),
                 _ => return,
             };
@@ -79,7 +97,17 @@ fn check_expr(&mut self, cx: &LateContext<'_>, e: &Expr<'_>) {
             // }
             // ```
             if !e.span.from_expansion() && !is_else_clause(cx.tcx, e) {
-                span_lint_and_help(cx, IF_NOT_ELSE, e.span, msg, None, help);
+                let then = snippet_with_applicability(cx, then.span, "{ .. }", &mut app);
+                let els = snippet_with_applicability(cx, els.span, "{ .. }", &mut app);
+                span_lint_and_sugg(
+                    cx,
+                    IF_NOT_ELSE,
+                    e.span,
+                    msg,
+                    "try",
+                    format!("if {cond} {els} else {then}"),
+                    app,
+                );
             }
         }
     }
@@ -1,15 +1,21 @@
-use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::{higher, is_integer_literal, peel_blocks_with_stmt, SpanlessEq};
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_suggestions};
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{
+    higher, is_else_clause, is_integer_literal, path_to_local_id, peel_blocks, peel_blocks_with_stmt, SpanlessEq,
+};
 use rustc_ast::ast::LitKind;
 use rustc_data_structures::packed::Pu128;
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, Expr, ExprKind, QPath};
+use rustc_hir::{Arm, BinOpKind, Expr, ExprKind, MatchSource, PatKind, QPath};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
 use rustc_session::declare_lint_pass;
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for implicit saturating subtraction.
+    /// Checks for implicit saturating subtraction, either by decrementing a variable after
+    /// checking it against its lower bound, or by an unsigned subtraction guarded against underflow
+    /// such as `if a > b { a - b } else { 0 }`.
     ///
     /// ### Why is this bad?
     /// Simplicity and readability. Instead we can easily use an builtin function.
@@ -33,6 +39,19 @@
     ///
     /// i = i.saturating_sub(1);
     /// ```
+    ///
+    /// ```no_run
+    /// # let a: u32 = 10;
+    /// # let b: u32 = 5;
+    /// let diff = if a > b { a - b } else { 0 };
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// # let a: u32 = 10;
+    /// # let b: u32 = 5;
+    /// let diff = a.saturating_sub(b);
+    /// ```
     #[clippy::version = "1.44.0"]
     pub IMPLICIT_SATURATING_SUB,
     style,
@@ -46,6 +65,9 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
         if expr.span.from_expansion() {
             return;
         }
+        if matches!(cx.typeck_results().expr_ty(expr).kind(), ty::Uint(_)) {
+            check_guarded_sub(cx, expr);
+        }
         if let Some(higher::If { cond, then, r#else: None }) = higher::If::hir(expr)
 
             // Check if the conditional expression is a binary operation
@@ -121,6 +143,104 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
     }
 }
 
+/// Checks for `if a > b { a - b } else { 0 }`, `if x != 0 { x - 1 } else { 0 }` and
+/// `match x { 0 => 0, _ => x - 1 }` producing an unsigned integer.
+fn check_guarded_sub<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+    let (minuend, subtrahend) = if let Some(higher::If {
+        cond,
+        then,
+        r#else: Some(r#else),
+    }) = higher::If::hir(expr)
+        && let ExprKind::Binary(op, cond_left, cond_right) = cond.kind
+    {
+        // `(big, small)` such that `big >= small` holds when the condition is true
+        let (big, small) = match op.node {
+            BinOpKind::Gt | BinOpKind::Ge | BinOpKind::Ne => (cond_left, cond_right),
+            BinOpKind::Lt | BinOpKind::Le => (cond_right, cond_left),
+            _ => return,
+        };
+        if is_integer_literal(peel_blocks(r#else), 0)
+            && let Some((minuend, subtrahend)) = binary_sub(then)
+            && SpanlessEq::new(cx).eq_expr(minuend, big)
+            && (SpanlessEq::new(cx).eq_expr(subtrahend, small)
+                || (matches!(op.node, BinOpKind::Gt | BinOpKind::Ne)
+                    && is_integer_literal(small, 0)
+                    && is_integer_literal(subtrahend, 1)))
+            && (op.node != BinOpKind::Ne || is_integer_literal(small, 0))
+        {
+            (minuend, subtrahend)
+        } else if is_integer_literal(peel_blocks(then), 0)
+            && op.node != BinOpKind::Ne
+            && let Some((minuend, subtrahend)) = binary_sub(r#else)
+            && SpanlessEq::new(cx).eq_expr(minuend, small)
+            && SpanlessEq::new(cx).eq_expr(subtrahend, big)
+        {
+            (minuend, subtrahend)
+        } else {
+            return;
+        }
+    } else if let ExprKind::Match(scrutinee, [zero_arm, sub_arm], MatchSource::Normal) = expr.kind
+        && is_zero_arm(zero_arm)
+        && sub_arm.guard.is_none()
+        && let Some((minuend, subtrahend)) = binary_sub(sub_arm.body)
+        && is_integer_literal(subtrahend, 1)
+        && match sub_arm.pat.kind {
+            PatKind::Wild => SpanlessEq::new(cx).eq_expr(minuend, scrutinee),
+            PatKind::Binding(_, id, _, None) => path_to_local_id(minuend, id),
+            _ => false,
+        }
+    {
+        (scrutinee, subtrahend)
+    } else {
+        return;
+    };
+
+    let mut app = Applicability::MachineApplicable;
+    let minuend = Sugg::hir_with_applicability(cx, minuend, "..", &mut app).maybe_par();
+    let subtrahend = Sugg::hir_with_applicability(cx, subtrahend, "..", &mut app);
+    let is_else_clause = is_else_clause(cx.tcx, expr);
+    let sugg = |sugg: String| {
+        let sugg = Sugg::NonParen(sugg.into());
+        // `else if ..` needs to become `else { .. }`
+        if is_else_clause {
+            sugg.blockify().to_string()
+        } else {
+            sugg.to_string()
+        }
+    };
+    span_lint_and_suggestions(
+        cx,
+        IMPLICIT_SATURATING_SUB,
+        expr.span,
+        "manually performing saturating subtraction",
+        [
+            (app, "try", sugg(format!("{minuend}.saturating_sub({subtrahend})"))),
+            (
+                Applicability::MaybeIncorrect,
+                "or",
+                sugg(format!("{minuend}.checked_sub({subtrahend}).unwrap_or(0)")),
+            ),
+        ],
+    );
+}
+
+fn binary_sub<'a>(expr: &'a Expr<'a>) -> Option<(&'a Expr<'a>, &'a Expr<'a>)> {
+    if let ExprKind::Binary(op, lhs, rhs) = peel_blocks(expr).kind
+        && op.node == BinOpKind::Sub
+    {
+        Some((lhs, rhs))
+    } else {
+        None
+    }
+}
+
+/// Checks for `0 => 0`.
+fn is_zero_arm(arm: &Arm<'_>) -> bool {
+    arm.guard.is_none()
+        && matches!(arm.pat.kind, PatKind::Lit(lit) if is_integer_literal(lit, 0))
+        && is_integer_literal(peel_blocks(arm.body), 0)
+}
+
 fn subtracts_one<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
     match peel_blocks_with_stmt(expr).kind {
         ExprKind::AssignOp(ref op1, target, value) => {
@@ -7,11 +7,12 @@
 use rustc_hir::{BindingMode, Mutability};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::declare_lint_pass;
+use rustc_span::Span;
 
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for variable declarations immediately followed by a
-    /// conditional affectation.
+    /// conditional affectation, in an `if` or in every arm of a `match`.
     ///
     /// ### Why is this bad?
     /// This is not idiomatic Rust.
@@ -120,29 +121,103 @@ fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx hir::Block<'_>) {
                     value=snippet(cx, value.span, "<value>"),
                     default=snippet(cx, default.span, "<default>"),
                 );
-                span_lint_hir_and_then(
+                emit_lint(
                     cx,
-                    USELESS_LET_IF_SEQ,
                     local.hir_id,
                     span,
                     "`if _ { .. } else { .. }` is an expression",
-                    |diag| {
-                        diag.span_suggestion(
-                            span,
-                            "it is more idiomatic to write",
-                            sug,
-                            Applicability::HasPlaceholders,
-                        );
-                        if !mutability.is_empty() {
-                            diag.note("you might not need `mut` at all");
-                        }
-                    },
+                    sug,
+                    !mutability.is_empty(),
+                );
+            } else if let hir::StmtKind::Let(local) = stmt.kind
+                && let hir::PatKind::Binding(mode, canonical_id, ident, None) = local.pat.kind
+                && let hir::StmtKind::Expr(match_) = next.kind
+                && let hir::ExprKind::Match(scrutinee, arms, hir::MatchSource::Normal) = match_.kind
+                && !arms.is_empty()
+                && !is_local_used(cx, scrutinee, canonical_id)
+                && let Some(arm_values) = arms
+                    .iter()
+                    .map(|arm| check_arm(cx, canonical_id, arm))
+                    .collect::<Option<Vec<_>>>()
+                && cx
+                    .typeck_results()
+                    .node_type(canonical_id)
+                    .is_freeze(cx.tcx, cx.param_env)
+            {
+                let span = stmt.span.to(match_.span);
+
+                let mutability = match mode {
+                    BindingMode(_, Mutability::Mut) => "<mut> ",
+                    _ => "",
+                };
+
+                let arms = arms
+                    .iter()
+                    .zip(arm_values)
+                    .map(|(arm, (multi_stmts, value))| {
+                        let guard = arm
+                            .guard
+                            .map_or(String::new(), |guard| format!(" if {}", snippet(cx, guard.span, "_")));
+                        let value = snippet(cx, value.span, "<value>");
+                        let body = if multi_stmts {
+                            format!("{{ ..; {value} }}")
+                        } else {
+                            value.into_owned()
+                        };
+                        format!("{}{guard} => {body}", snippet(cx, arm.pat.span, "_"))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let sug = format!(
+                    "let {mutability}{name} = match {scrutinee} {{ {arms} }};",
+                    name = ident.name,
+                    scrutinee = snippet(cx, scrutinee.span, "_"),
+                );
+                emit_lint(
+                    cx,
+                    local.hir_id,
+                    span,
+                    "`match` is an expression",
+                    sug,
+                    !mutability.is_empty(),
                 );
             }
         }
     }
 }
 
+fn emit_lint(cx: &LateContext<'_>, hir_id: hir::HirId, span: Span, msg: &'static str, sug: String, is_mut: bool) {
+    span_lint_hir_and_then(cx, USELESS_LET_IF_SEQ, hir_id, span, msg, |diag| {
+        diag.span_suggestion(
+            span,
+            "it is more idiomatic to write",
+            sug,
+            Applicability::HasPlaceholders,
+        );
+        if is_mut {
+            diag.note("you might not need `mut` at all");
+        }
+    });
+}
+
+/// Checks that the arm's body assigns to `decl` as its last statement, without using `decl`
+/// anywhere else. Returns whether the body has other statements, and the assigned value.
+fn check_arm<'tcx>(
+    cx: &LateContext<'tcx>,
+    decl: hir::HirId,
+    arm: &'tcx hir::Arm<'_>,
+) -> Option<(bool, &'tcx hir::Expr<'tcx>)> {
+    if let hir::ExprKind::Block(block, _) = arm.body.kind
+        && arm.guard.map_or(true, |guard| !is_local_used(cx, guard, decl))
+        && let Some(value) = check_assign(cx, decl, block)
+        && !is_local_used(cx, value, decl)
+    {
+        Some((block.stmts.len() > 1, value))
+    } else {
+        None
+    }
+}
+
 fn check_assign<'tcx>(
     cx: &LateContext<'tcx>,
     decl: hir::HirId,
@@ -203,6 +203,7 @@
 mod manual_async_fn;
 mod manual_bits;
 mod manual_clamp;
+mod manual_expect;
 mod manual_float_methods;
 mod manual_hash_one;
 mod manual_is_ascii_check;
@@ -330,6 +331,7 @@
 mod single_range_in_vec_init;
 mod size_of_in_element_count;
 mod size_of_ref;
+mod slicing_instead_of_iter_range;
 mod slow_vector_initialization;
 mod std_instead_of_core;
 mod string_patterns;
@@ -933,5 +935,8 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(set_contains_or_insert::SetContainsOrInsert));
     store.register_early_pass(|| Box::new(byte_char_slices::ByteCharSlice));
     store.register_early_pass(|| Box::new(cfg_not_test::CfgNotTest));
+    store.register_late_pass(|_| Box::new(slicing_instead_of_iter_range::SlicingInsteadOfIterRange));
+    let format_args = format_args_storage.clone();
+    store.register_late_pass(move |_| Box::new(manual_expect::ManualExpect::new(format_args.clone())));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
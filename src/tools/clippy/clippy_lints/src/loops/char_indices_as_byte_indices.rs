@@ -0,0 +1,43 @@
+use super::CHAR_INDICES_AS_BYTE_INDICES;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_to_local_id;
+use clippy_utils::visitors::for_each_expr;
+use core::ops::ControlFlow;
+use rustc_hir::{BindingMode, Expr, ExprKind, Pat, PatKind};
+use rustc_lint::LateContext;
+
+/// Checks for the `CHAR_INDICES_AS_BYTE_INDICES` lint.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, pat: &Pat<'tcx>, arg: &Expr<'_>, body: &'tcx Expr<'tcx>) {
+    if let PatKind::Tuple([index, _], _) = pat.kind
+        && let PatKind::Binding(BindingMode::NONE, index_id, _, None) = index.kind
+        && let ExprKind::MethodCall(method, recv, [], _) = arg.kind
+        && method.ident.name.as_str() == "char_indices"
+        && is_str(cx, recv)
+    {
+        let _: Option<!> = for_each_expr(cx, body, |e| {
+            if let ExprKind::MethodCall(count_method, chars, [count], _) = e.kind
+                && matches!(count_method.ident.name.as_str(), "nth" | "skip" | "take")
+                && path_to_local_id(count, index_id)
+                && let ExprKind::MethodCall(chars_method, chars_recv, [], _) = chars.kind
+                && chars_method.ident.name.as_str() == "chars"
+                && is_str(cx, chars_recv)
+            {
+                span_lint_and_then(
+                    cx,
+                    CHAR_INDICES_AS_BYTE_INDICES,
+                    count.span,
+                    "using a byte index from `char_indices` as a number of `char`s",
+                    |diag| {
+                        diag.span_note(index.span, "this is a byte offset, not a `char` position");
+                        diag.help("use `chars().enumerate()` to get `char` positions instead");
+                    },
+                );
+            }
+            ControlFlow::Continue(())
+        });
+    }
+}
+
+fn is_str(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.typeck_results().expr_ty_adjusted(expr).peel_refs().is_str()
+}
@@ -57,9 +57,11 @@ pub(super) fn check(cx: &LateContext<'_>, self_arg: &Expr<'_>, call_expr: &Expr<
                 ..
             },
         ] => AdjustKind::borrow(mutbl),
+        // An overloaded deref means `IntoIterator` is only implemented for the `Deref` target, so
+        // the loop can't iterate over `self_arg` directly.
         &[
             Adjustment {
-                kind: Adjust::Deref(_), ..
+                kind: Adjust::Deref(None), ..
             },
             Adjustment {
                 kind: Adjust::Borrow(AutoBorrow::Ref(_, mutbl)),
@@ -3,11 +3,12 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::implements_trait;
-use clippy_utils::{higher, is_res_lang_ctor, path_res, peel_blocks_with_stmt};
+use clippy_utils::usage::is_potentially_mutated;
+use clippy_utils::{higher, is_res_lang_ctor, path_res, path_to_local, peel_blocks_with_stmt};
 use rustc_errors::Applicability;
 use rustc_hir::def::Res;
 use rustc_hir::lang_items::LangItem;
-use rustc_hir::{BindingMode, Block, Expr, ExprKind, HirId, Node, Pat, PatKind, Stmt, StmtKind};
+use rustc_hir::{BindingMode, Block, Destination, Expr, ExprKind, HirId, LetStmt, Node, Pat, PatKind, Stmt, StmtKind};
 use rustc_lint::LateContext;
 use rustc_span::Span;
 
@@ -20,8 +21,6 @@ pub(super) fn check<'tcx>(
     expr: &'tcx Expr<'_>,
 ) {
     let inner_expr = peel_blocks_with_stmt(body);
-    // Check for the specific case that the result is returned and optimize suggestion for that (more
-    // cases can be added later)
     if let Some(higher::If {
         cond,
         then,
@@ -29,73 +28,141 @@ pub(super) fn check<'tcx>(
     }) = higher::If::hir(inner_expr)
         && let Some(binding_id) = get_binding(pat)
         && let ExprKind::Block(block, _) = then.kind
-        && let [stmt] = block.stmts
-        && let StmtKind::Semi(semi) = stmt.kind
-        && let ExprKind::Ret(Some(ret_value)) = semi.kind
-        && let ExprKind::Call(ctor, [inner_ret]) = ret_value.kind
-        && is_res_lang_ctor(cx, path_res(cx, ctor), LangItem::OptionSome)
-        && path_res(cx, inner_ret) == Res::Local(binding_id)
-        && let Some((last_stmt, last_ret)) = last_stmt_and_ret(cx, expr)
+        && block.expr.is_none()
     {
-        let mut applicability = Applicability::MachineApplicable;
-        let mut snippet = make_iterator_snippet(cx, arg, &mut applicability);
-        // Checks if `pat` is a single reference to a binding (`&x`)
-        let is_ref_to_binding =
-            matches!(pat.kind, PatKind::Ref(inner, _) if matches!(inner.kind, PatKind::Binding(..)));
-        // If `pat` is not a binding or a reference to a binding (`x` or `&x`)
-        // we need to map it to the binding returned by the function (i.e. `.map(|(x, _)| x)`)
-        if !(matches!(pat.kind, PatKind::Binding(..)) || is_ref_to_binding) {
-            snippet.push_str(
-                &format!(
-                    ".map(|{}| {})",
-                    snippet_with_applicability(cx, pat.span, "..", &mut applicability),
-                    snippet_with_applicability(cx, inner_ret.span, "..", &mut applicability),
-                )[..],
-            );
+        match block.stmts {
+            // `for .. { if .. { return Some(..); } } None` at the end of a function
+            [stmt] => {
+                if let StmtKind::Semi(semi) = stmt.kind
+                    && let ExprKind::Ret(Some(ret_value)) = semi.kind
+                    && let Some(inner_ret) = some_of_binding(cx, ret_value, binding_id)
+                    && let Some((last_stmt, last_ret)) = last_stmt_and_ret(cx, expr)
+                {
+                    let mut applicability = Applicability::MachineApplicable;
+                    let snippet = find_snippet(cx, pat, arg, cond, inner_ret, &mut applicability);
+                    // Extends to `last_stmt` to include semicolon in case of `return None;`
+                    emit(cx, span.to(last_stmt.span).to(last_ret.span), snippet, applicability);
+                }
+            },
+            // `let mut x = None; for .. { if .. { x = Some(..); break; } }`
+            [assign, brk] => {
+                if let StmtKind::Semi(assign) = assign.kind
+                    && let ExprKind::Assign(lhs, rhs, _) = assign.kind
+                    && let Some(result_id) = path_to_local(lhs)
+                    && let Some(inner_ret) = some_of_binding(cx, rhs, binding_id)
+                    && let StmtKind::Semi(brk) = brk.kind
+                    && let ExprKind::Break(Destination { label: None, .. }, None) = brk.kind
+                    && let Some((let_stmt, local, after)) = let_stmt_before_loop(cx, expr, result_id)
+                    && let PatKind::Binding(_, _, ident, None) = local.pat.kind
+                {
+                    let mut applicability = Applicability::MachineApplicable;
+                    let snippet = find_snippet(cx, pat, arg, cond, inner_ret, &mut applicability);
+                    let mutability = if after.iter().any(|&e| is_potentially_mutated(result_id, e, cx)) {
+                        "mut "
+                    } else {
+                        ""
+                    };
+                    let ty = local.ty.map_or(String::new(), |ty| {
+                        format!(
+                            ": {}",
+                            snippet_with_applicability(cx, ty.span, "..", &mut applicability)
+                        )
+                    });
+                    emit(
+                        cx,
+                        let_stmt.span.to(span),
+                        format!("let {mutability}{ident}{ty} = {snippet};"),
+                        applicability,
+                    );
+                }
+            },
+            _ => {},
         }
-        let ty = cx.typeck_results().expr_ty(inner_ret);
-        if cx
-            .tcx
-            .lang_items()
-            .copy_trait()
-            .map_or(false, |id| implements_trait(cx, ty, id, &[]))
-        {
-            snippet.push_str(
-                &format!(
-                    ".find(|{}{}| {})",
-                    "&".repeat(1 + usize::from(is_ref_to_binding)),
-                    snippet_with_applicability(cx, inner_ret.span, "..", &mut applicability),
-                    snippet_with_applicability(cx, cond.span, "..", &mut applicability),
-                )[..],
-            );
-            if is_ref_to_binding {
-                snippet.push_str(".copied()");
+    }
+}
+
+fn emit(cx: &LateContext<'_>, lint_span: Span, snippet: String, applicability: Applicability) {
+    span_lint_and_then(
+        cx,
+        MANUAL_FIND,
+        lint_span,
+        "manual implementation of `Iterator::find`",
+        |diag| {
+            if applicability == Applicability::MaybeIncorrect {
+                diag.note("you may need to dereference some variables");
             }
-        } else {
-            applicability = Applicability::MaybeIncorrect;
-            snippet.push_str(
-                &format!(
-                    ".find(|{}| {})",
-                    snippet_with_applicability(cx, inner_ret.span, "..", &mut applicability),
-                    snippet_with_applicability(cx, cond.span, "..", &mut applicability),
-                )[..],
-            );
+            diag.span_suggestion(lint_span, "replace with an iterator", snippet, applicability);
+        },
+    );
+}
+
+// Returns `x` if `expr` is `Some(x)` and `x` is the given binding
+fn some_of_binding<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    binding_id: HirId,
+) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Call(ctor, [inner]) = expr.kind
+        && is_res_lang_ctor(cx, path_res(cx, ctor), LangItem::OptionSome)
+        && path_res(cx, inner) == Res::Local(binding_id)
+    {
+        Some(inner)
+    } else {
+        None
+    }
+}
+
+fn find_snippet<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    cond: &'tcx Expr<'_>,
+    inner_ret: &'tcx Expr<'_>,
+    applicability: &mut Applicability,
+) -> String {
+    let mut snippet = make_iterator_snippet(cx, arg, applicability);
+    // Checks if `pat` is a single reference to a binding (`&x`)
+    let is_ref_to_binding = matches!(pat.kind, PatKind::Ref(inner, _) if matches!(inner.kind, PatKind::Binding(..)));
+    // If `pat` is not a binding or a reference to a binding (`x` or `&x`)
+    // we need to map it to the binding returned by the function (i.e. `.map(|(x, _)| x)`)
+    if !(matches!(pat.kind, PatKind::Binding(..)) || is_ref_to_binding) {
+        snippet.push_str(
+            &format!(
+                ".map(|{}| {})",
+                snippet_with_applicability(cx, pat.span, "..", applicability),
+                snippet_with_applicability(cx, inner_ret.span, "..", applicability),
+            )[..],
+        );
+    }
+    let ty = cx.typeck_results().expr_ty(inner_ret);
+    if cx
+        .tcx
+        .lang_items()
+        .copy_trait()
+        .map_or(false, |id| implements_trait(cx, ty, id, &[]))
+    {
+        snippet.push_str(
+            &format!(
+                ".find(|{}{}| {})",
+                "&".repeat(1 + usize::from(is_ref_to_binding)),
+                snippet_with_applicability(cx, inner_ret.span, "..", applicability),
+                snippet_with_applicability(cx, cond.span, "..", applicability),
+            )[..],
+        );
+        if is_ref_to_binding {
+            snippet.push_str(".copied()");
         }
-        // Extends to `last_stmt` to include semicolon in case of `return None;`
-        let lint_span = span.to(last_stmt.span).to(last_ret.span);
-        span_lint_and_then(
-            cx,
-            MANUAL_FIND,
-            lint_span,
-            "manual implementation of `Iterator::find`",
-            |diag| {
-                if applicability == Applicability::MaybeIncorrect {
-                    diag.note("you may need to dereference some variables");
-                }
-                diag.span_suggestion(lint_span, "replace with an iterator", snippet, applicability);
-            },
+    } else {
+        *applicability = Applicability::MaybeIncorrect;
+        snippet.push_str(
+            &format!(
+                ".find(|{}| {})",
+                snippet_with_applicability(cx, inner_ret.span, "..", applicability),
+                snippet_with_applicability(cx, cond.span, "..", applicability),
+            )[..],
         );
     }
+    snippet
 }
 
 fn get_binding(pat: &Pat<'_>) -> Option<HirId> {
@@ -152,3 +219,37 @@ fn extract<'tcx>(block: &Block<'tcx>) -> Option<(&'tcx Stmt<'tcx>, &'tcx Expr<'t
         None
     }
 }
+
+// Returns the `let` statement declaring `result_id` as `None` right before the loop, and the
+// expressions evaluated after the loop in the same block
+fn let_stmt_before_loop<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    result_id: HirId,
+) -> Option<(&'tcx Stmt<'tcx>, &'tcx LetStmt<'tcx>, Vec<&'tcx Expr<'tcx>>)> {
+    let mut parent_iter = cx.tcx.hir().parent_iter(expr.hir_id);
+    if let Some((loop_stmt_id, Node::Stmt(..))) = parent_iter.next()
+        && let Some((_, Node::Block(block))) = parent_iter.next()
+        && let Some(idx) = block.stmts.iter().position(|stmt| stmt.hir_id == loop_stmt_id)
+        && let Some(let_stmt) = idx.checked_sub(1).map(|idx| &block.stmts[idx])
+        && let StmtKind::Let(local) = let_stmt.kind
+        && local.pat.hir_id == result_id
+        && local.els.is_none()
+        && let Some(init) = local.init
+        && is_res_lang_ctor(cx, path_res(cx, init), LangItem::OptionNone)
+        && let_stmt.span.eq_ctxt(expr.span)
+    {
+        let after = block.stmts[idx + 1..]
+            .iter()
+            .filter_map(|stmt| match stmt.kind {
+                StmtKind::Let(local) => local.init,
+                StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                StmtKind::Item(_) => None,
+            })
+            .chain(block.expr)
+            .collect();
+        Some((let_stmt, local, after))
+    } else {
+        None
+    }
+}
@@ -1,3 +1,4 @@
+mod char_indices_as_byte_indices;
 mod empty_loop;
 mod explicit_counter_loop;
 mod explicit_into_iter_loop;
@@ -586,7 +587,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for manual implementations of Iterator::find
+    /// Checks for manual implementations of Iterator::find, which either return the first
+    /// match from a function or store it in a variable before breaking out of the loop.
     ///
     /// ### Why is this bad?
     /// It doesn't affect performance, but using `find` is shorter and easier to read.
@@ -714,6 +716,36 @@
     "possibly unintended infinite loop"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for` loops over `str::char_indices` that pass the index to
+    /// `chars().nth()`, `chars().skip()` or `chars().take()`.
+    ///
+    /// ### Why is this bad?
+    /// The index yielded by `char_indices` is a byte offset into the string, while these methods
+    /// count `char`s. The two only agree as long as the string is ASCII, so the code silently
+    /// does the wrong thing on other input.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let s = "héllo";
+    /// for (i, c) in s.char_indices() {
+    ///     let rest: String = s.chars().skip(i).collect();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let s = "héllo";
+    /// for (i, c) in s.char_indices() {
+    ///     let rest = &s[i..];
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub CHAR_INDICES_AS_BYTE_INDICES,
+    suspicious,
+    "using a byte index from `char_indices` as a number of `char`s"
+}
+
 pub struct Loops {
     msrv: Msrv,
     enforce_iter_loop_reborrow: bool,
@@ -750,6 +782,7 @@ pub fn new(conf: &'static Conf) -> Self {
     MANUAL_WHILE_LET_SOME,
     UNUSED_ENUMERATE_INDEX,
     INFINITE_LOOP,
+    CHAR_INDICES_AS_BYTE_INDICES,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Loops {
@@ -834,6 +867,7 @@ fn check_for_loop<'tcx>(
         manual_flatten::check(cx, pat, arg, body, span);
         manual_find::check(cx, pat, arg, body, span, expr);
         unused_enumerate_index::check(cx, pat, arg, body);
+        char_indices_as_byte_indices::check(cx, pat, arg, body);
     }
 
     fn check_for_loop_arg(&self, cx: &LateContext<'_>, _: &Pat<'_>, arg: &Expr<'_>) {
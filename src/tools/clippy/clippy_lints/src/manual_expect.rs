@@ -0,0 +1,132 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::{format_args_inputs_span, is_panic, root_macro_call, FormatArgsStorage};
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{eq_expr_value, peel_blocks_with_stmt};
+use rustc_ast::FormatArgsPiece;
+use rustc_errors::Applicability;
+use rustc_hir::{Block, Expr, ExprKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::impl_lint_pass;
+use rustc_span::{sym, Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for an `if` that panics when an `Option` is `None` or a `Result` is `Err`,
+    /// directly followed by a call to `unwrap()` on the same value.
+    ///
+    /// ### Why is this bad?
+    /// `expect()` performs the same check and panics with the given message, in a single call.
+    ///
+    /// ### Known problems
+    /// `Result::expect` appends the `Debug` representation of the error to the panic
+    /// message, so the message changes slightly. Messages with format arguments are
+    /// suggested as `unwrap_or_else(|| panic!(..))` instead.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let result: Result<u32, ()> = Ok(1);
+    /// if result.is_err() {
+    ///     panic!("no value");
+    /// }
+    /// let value = result.unwrap();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let result: Result<u32, ()> = Ok(1);
+    /// let value = result.expect("no value");
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub MANUAL_EXPECT,
+    complexity,
+    "panicking on `is_none()`/`is_err()` right before calling `unwrap()`"
+}
+
+pub struct ManualExpect {
+    format_args: FormatArgsStorage,
+}
+
+impl ManualExpect {
+    pub fn new(format_args: FormatArgsStorage) -> Self {
+        Self { format_args }
+    }
+}
+
+impl_lint_pass!(ManualExpect => [MANUAL_EXPECT]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualExpect {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (idx, stmt) in block.stmts.iter().enumerate() {
+            if let StmtKind::Expr(if_expr) | StmtKind::Semi(if_expr) = stmt.kind
+                && let ExprKind::If(cond, then, None) = if_expr.kind
+                && !if_expr.span.from_expansion()
+                && let ExprKind::MethodCall(check, checked, [], _) = cond.peel_drop_temps().kind
+                && is_failure_check(cx, check.ident.name, checked)
+                && let panic_expr = peel_blocks_with_stmt(then)
+                && let Some(panic_call) = root_macro_call(panic_expr.span)
+                && is_panic(cx, panic_call.def_id)
+                && let Some(format_args) = self.format_args.get(cx, panic_expr, panic_call.expn)
+                && let Some((next, next_span)) = next_expr(block, idx + 1)
+                && let ExprKind::MethodCall(unwrap, unwrapped, [], _) = next.kind
+                && unwrap.ident.name == sym::unwrap
+                && !next.span.from_expansion()
+                && eq_expr_value(cx, checked, unwrapped)
+            {
+                let mut applicability = Applicability::MachineApplicable;
+                let inputs =
+                    snippet_with_applicability(cx, format_args_inputs_span(format_args), "..", &mut applicability);
+                // Only a string literal without placeholders or escaped braces can be passed to `expect` as is,
+                // anything else keeps the `panic!` in a closure.
+                let (method, sugg) = if format_args
+                    .template
+                    .iter()
+                    .all(|piece| matches!(piece, FormatArgsPiece::Literal(_)))
+                    && !inputs.contains(['{', '}'])
+                {
+                    ("expect", format!("expect({inputs})"))
+                } else {
+                    // The closure borrows the format arguments, which may conflict with the unwrapped value.
+                    applicability = Applicability::MaybeIncorrect;
+                    let param = if check.ident.name == sym!(is_err) { "_" } else { "" };
+                    ("unwrap_or_else", format!("unwrap_or_else(|{param}| panic!({inputs}))"))
+                };
+                span_lint_and_then(
+                    cx,
+                    MANUAL_EXPECT,
+                    if_expr.span,
+                    "manually panicking before calling `unwrap()`",
+                    |diag| {
+                        diag.multipart_suggestion(
+                            format!("use `{method}()` instead"),
+                            vec![
+                                (stmt.span.until(next_span), String::new()),
+                                (unwrap.ident.span.with_hi(next.span.hi()), sugg),
+                            ],
+                            applicability,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Checks if `method` is `is_none` called on an `Option` or `is_err` called on a `Result`.
+fn is_failure_check(cx: &LateContext<'_>, method: Symbol, recv: &Expr<'_>) -> bool {
+    let recv_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+    (method == sym!(is_none) && is_type_diagnostic_item(cx, recv_ty, sym::Option))
+        || (method == sym!(is_err) && is_type_diagnostic_item(cx, recv_ty, sym::Result))
+}
+
+/// Gets the expression the statement at `idx` evaluates (the initializer for a `let`), falling
+/// back to the block's trailing expression. Also returns the span of the whole statement.
+fn next_expr<'tcx>(block: &Block<'tcx>, idx: usize) -> Option<(&'tcx Expr<'tcx>, Span)> {
+    match block.stmts.get(idx) {
+        Some(stmt) => match stmt.kind {
+            StmtKind::Let(local) if local.els.is_none() => local.init.map(|init| (init, stmt.span)),
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some((e, stmt.span)),
+            StmtKind::Let(_) | StmtKind::Item(_) => None,
+        },
+        None => block.expr.map(|e| (e, e.span)),
+    }
+}
@@ -21,7 +21,8 @@
     ///
     /// ### Known issues
     ///
-    /// Currently, the lint only catches shifts by constant amount.
+    /// Shifts by a non-constant amount `n` are only caught when the complementary
+    /// shift amount is written as `BITS - n` (or the literal bit width minus `n`).
     ///
     /// ### Example
     /// ```no_run
@@ -56,24 +57,44 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     }
 }
 
+enum ShiftAmount<'tcx> {
+    Const(u128),
+    NonConst(&'tcx Expr<'tcx>),
+}
+
 fn parse_shift<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx Expr<'tcx>,
-) -> Option<(ShiftDirection, u128, &'tcx Expr<'tcx>)> {
+) -> Option<(ShiftDirection, ShiftAmount<'tcx>, &'tcx Expr<'tcx>)> {
     if let ExprKind::Binary(op, l, r) = expr.kind {
         let dir = match op.node {
             BinOpKind::Shl => ShiftDirection::Left,
             BinOpKind::Shr => ShiftDirection::Right,
             _ => return None,
         };
-        let const_expr = ConstEvalCtxt::new(cx).eval(r)?;
-        if let Constant::Int(shift) = const_expr {
-            return Some((dir, shift, l));
-        }
+        let amount = match ConstEvalCtxt::new(cx).eval(r) {
+            Some(Constant::Int(shift)) => ShiftAmount::Const(shift),
+            Some(_) => return None,
+            None => ShiftAmount::NonConst(r),
+        };
+        return Some((dir, amount, l));
     }
     None
 }
 
+/// Checks if `expr` is `bit_width - amount`, with `bit_width` given either as a literal or as
+/// the `BITS` constant of the integer type.
+fn is_complement_of<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'tcx>, amount: &Expr<'tcx>, bit_width: u64) -> bool {
+    if let ExprKind::Binary(op, l, r) = expr.kind
+        && op.node == BinOpKind::Sub
+        && let Some(Constant::Int(width)) = ConstEvalCtxt::new(cx).eval(l)
+    {
+        width == u128::from(bit_width) && clippy_utils::eq_expr_value(cx, r, amount)
+    } else {
+        false
+    }
+}
+
 impl LateLintPass<'_> for ManualRotate {
     fn check_expr<'tcx>(&mut self, cx: &LateContext<'tcx>, expr: &Expr<'tcx>) {
         if let ExprKind::Binary(op, l, r) = expr.kind
@@ -94,24 +115,45 @@ fn check_expr<'tcx>(&mut self, cx: &LateContext<'tcx>, expr: &Expr<'tcx>) {
             }) else {
                 return;
             };
-            if l_amount + r_amount == u128::from(bit_width) {
-                let (shift_function, amount) = if l_amount < r_amount {
-                    (l_shift_dir, l_amount)
-                } else {
-                    (r_shift_dir, r_amount)
-                };
-                let mut applicability = Applicability::MachineApplicable;
-                let expr_sugg = sugg::Sugg::hir_with_applicability(cx, l_expr, "_", &mut applicability).maybe_par();
-                span_lint_and_sugg(
-                    cx,
-                    MANUAL_ROTATE,
-                    expr.span,
-                    "there is no need to manually implement bit rotation",
-                    "this expression can be rewritten as",
-                    format!("{expr_sugg}.{shift_function}({amount})"),
-                    Applicability::MachineApplicable,
-                );
-            }
+            let mut applicability = Applicability::MachineApplicable;
+            let (shift_function, amount) = match (l_amount, r_amount) {
+                (ShiftAmount::Const(l_amount), ShiftAmount::Const(r_amount)) => {
+                    if l_amount + r_amount != u128::from(bit_width) {
+                        return;
+                    }
+                    if l_amount < r_amount {
+                        (l_shift_dir, l_amount.to_string())
+                    } else {
+                        (r_shift_dir, r_amount.to_string())
+                    }
+                },
+                (ShiftAmount::NonConst(l_amount), ShiftAmount::NonConst(r_amount)) => {
+                    // `rotate_left` and `rotate_right` take the amount as `u32`
+                    let (shift_function, amount) = if is_complement_of(cx, r_amount, l_amount, bit_width) {
+                        (l_shift_dir, l_amount)
+                    } else if is_complement_of(cx, l_amount, r_amount, bit_width) {
+                        (r_shift_dir, r_amount)
+                    } else {
+                        return;
+                    };
+                    if !matches!(cx.typeck_results().expr_ty(amount).kind(), ty::Uint(ty::UintTy::U32)) {
+                        return;
+                    }
+                    let amount = sugg::Sugg::hir_with_applicability(cx, amount, "_", &mut applicability);
+                    (shift_function, amount.to_string())
+                },
+                _ => return,
+            };
+            let expr_sugg = sugg::Sugg::hir_with_applicability(cx, l_expr, "_", &mut applicability).maybe_par();
+            span_lint_and_sugg(
+                cx,
+                MANUAL_ROTATE,
+                expr.span,
+                "there is no need to manually implement bit rotation",
+                "this expression can be rewritten as",
+                format!("{expr_sugg}.{shift_function}({amount})"),
+                applicability,
+            );
         }
     }
 }
@@ -1,13 +1,17 @@
-use super::manual_utils::{check_with, SomeExpr};
+use super::manual_utils::{can_pass_as_func, check_with, SomeExpr};
 use super::MANUAL_MAP;
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{can_move_expr_to_closure, is_res_lang_ctor, path_res, path_to_local_id, peel_blocks};
 
-use clippy_utils::{is_res_lang_ctor, path_res};
-
-use rustc_hir::LangItem::OptionSome;
-use rustc_hir::{Arm, Block, BlockCheckMode, Expr, ExprKind, Pat, UnsafeSource};
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionSome, ResultErr, ResultOk};
+use rustc_hir::{Arm, BindingMode, Block, BlockCheckMode, Expr, ExprKind, HirId, LangItem, Pat, PatKind, UnsafeSource};
 use rustc_lint::LateContext;
-use rustc_span::SyntaxContext;
+use rustc_middle::ty;
+use rustc_span::{sym, Symbol, SyntaxContext};
 
 pub(super) fn check_match<'tcx>(
     cx: &LateContext<'tcx>,
@@ -20,6 +24,7 @@ pub(super) fn check_match<'tcx>(
         && arm2.guard.is_none()
     {
         check(cx, expr, scrutinee, arm1.pat, arm1.body, Some(arm2.pat), arm2.body);
+        check_result(cx, expr, scrutinee, arm1, arm2);
     }
 }
 
@@ -114,3 +119,93 @@ fn get_some_expr_internal<'tcx>(
     }
     get_some_expr_internal(cx, expr, false, ctxt)
 }
+
+/// Checks for `match res { Ok(x) => Ok(f(x)), Err(e) => Err(e) }`.
+fn check_result<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    scrutinee: &'tcx Expr<'_>,
+    arm1: &'tcx Arm<'_>,
+    arm2: &'tcx Arm<'_>,
+) {
+    let scrutinee_ty = cx.typeck_results().expr_ty(scrutinee);
+    let expr_ty = cx.typeck_results().expr_ty(expr);
+    if let ty::Adt(_, scrutinee_args) = scrutinee_ty.kind()
+        && let ty::Adt(_, expr_args) = expr_ty.kind()
+        && is_type_diagnostic_item(cx, scrutinee_ty, sym::Result)
+        && is_type_diagnostic_item(cx, expr_ty, sym::Result)
+        // The error is passed through unchanged, so it must not be converted.
+        && scrutinee_args.type_at(1) == expr_args.type_at(1)
+        && let ctxt = expr.span.ctxt()
+        && let Some((ok_arm, (ok_id, ok_name, ok_mode))) = [arm1, arm2]
+            .into_iter()
+            .find_map(|arm| Some((arm, binding_in_ctor(cx, arm.pat, ResultOk, ctxt)?)))
+        && let err_arm = if ok_arm.hir_id == arm1.hir_id { arm2 } else { arm1 }
+        && let Some((err_id, ..)) = binding_in_ctor(cx, err_arm.pat, ResultErr, ctxt)
+        && let Some(err_value) = ctor_arg(cx, err_arm.body, ResultErr, ctxt)
+        && path_to_local_id(err_value, err_id)
+        && let Some(ok_value) = ctor_arg(cx, ok_arm.body, ResultOk, ctxt)
+        // `Ok(x) => Ok(x)` is handled by `needless_match`.
+        && !path_to_local_id(ok_value, ok_id)
+        && cx.typeck_results().expr_adjustments(ok_value).is_empty()
+        && can_move_expr_to_closure(cx, ok_value).is_some()
+    {
+        let mut app = Applicability::MachineApplicable;
+        let scrutinee_str = Sugg::hir_with_context(cx, scrutinee, ctxt, "..", &mut app).maybe_par();
+        let body_str = if let Some(func) = can_pass_as_func(cx, ok_id, ok_value)
+            && func.span.eq_ctxt(ok_value.span)
+        {
+            snippet_with_context(cx, func.span, ctxt, "..", &mut app).0.into_owned()
+        } else {
+            let annotation = if ok_mode == BindingMode::MUT { "mut " } else { "" };
+            let value = Sugg::hir_with_context(cx, ok_value, ctxt, "..", &mut app);
+            format!("|{annotation}{ok_name}| {value}")
+        };
+        span_lint_and_sugg(
+            cx,
+            MANUAL_MAP,
+            expr.span,
+            "manual implementation of `Result::map`",
+            "try",
+            format!("{scrutinee_str}.map({body_str})"),
+            app,
+        );
+    }
+}
+
+/// If `pat` is `Ok(x)` or `Err(x)` (depending on `ctor`) with a plain binding `x`, returns that
+/// binding.
+fn binding_in_ctor(
+    cx: &LateContext<'_>,
+    pat: &Pat<'_>,
+    ctor: LangItem,
+    ctxt: SyntaxContext,
+) -> Option<(HirId, Symbol, BindingMode)> {
+    if let PatKind::TupleStruct(ref qpath, [inner], _) = pat.kind
+        && pat.span.ctxt() == ctxt
+        && is_res_lang_ctor(cx, cx.qpath_res(qpath, pat.hir_id), ctor)
+        && let PatKind::Binding(mode @ (BindingMode::NONE | BindingMode::MUT), id, ident, None) = inner.kind
+    {
+        Some((id, ident.name, mode))
+    } else {
+        None
+    }
+}
+
+/// If `expr` is `Ok(value)` or `Err(value)` (depending on `ctor`), returns `value`.
+fn ctor_arg<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    ctor: LangItem,
+    ctxt: SyntaxContext,
+) -> Option<&'tcx Expr<'tcx>> {
+    let expr = peel_blocks(expr);
+    if let ExprKind::Call(callee, [arg]) = expr.kind
+        && expr.span.ctxt() == ctxt
+        && is_res_lang_ctor(cx, path_res(cx, callee), ctor)
+    {
+        Some(arg)
+    } else {
+        None
+    }
+}
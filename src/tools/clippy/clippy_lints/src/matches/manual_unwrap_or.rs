@@ -1,13 +1,17 @@
+use std::ops::ControlFlow;
+
 use clippy_utils::consts::ConstEvalCtxt;
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
 use clippy_utils::source::{indent_of, reindent_multiline, SpanRangeExt};
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::usage::contains_return_break_continue_macro;
-use clippy_utils::{is_res_lang_ctor, path_to_local_id, peel_blocks, sugg};
+use clippy_utils::visitors::{for_each_expr_without_closures, is_local_used};
+use clippy_utils::{is_res_lang_ctor, path_to_local, path_to_local_id, peel_blocks, sugg};
 use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::LangItem::{OptionNone, ResultErr};
-use rustc_hir::{Arm, Expr, Pat, PatKind};
+use rustc_hir::{Arm, Expr, ExprKind, Pat, PatKind};
 use rustc_lint::LateContext;
 use rustc_middle::ty::Ty;
 use rustc_span::sym;
@@ -17,8 +21,8 @@
 pub(super) fn check_match<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx Expr<'tcx>,
-    scrutinee: &'tcx Expr<'_>,
-    arms: &'tcx [Arm<'_>],
+    scrutinee: &'tcx Expr<'tcx>,
+    arms: &'tcx [Arm<'tcx>],
 ) {
     let ty = cx.typeck_results().expr_ty(scrutinee);
     if let Some((or_arm, unwrap_arm)) = applicable_or_arm(cx, arms) {
@@ -28,11 +32,11 @@ pub(super) fn check_match<'tcx>(
 
 pub(super) fn check_if_let<'tcx>(
     cx: &LateContext<'tcx>,
-    expr: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'tcx>,
     let_pat: &'tcx Pat<'_>,
-    let_expr: &'tcx Expr<'_>,
-    then_expr: &'tcx Expr<'_>,
-    else_expr: &'tcx Expr<'_>,
+    let_expr: &'tcx Expr<'tcx>,
+    then_expr: &'tcx Expr<'tcx>,
+    else_expr: &'tcx Expr<'tcx>,
 ) {
     let ty = cx.typeck_results().expr_ty(let_expr);
     let then_ty = cx.typeck_results().expr_ty(then_expr);
@@ -51,11 +55,11 @@ pub(super) fn check_if_let<'tcx>(
 
 fn check_and_lint<'tcx>(
     cx: &LateContext<'tcx>,
-    expr: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'tcx>,
     let_pat: &'tcx Pat<'_>,
-    let_expr: &'tcx Expr<'_>,
-    then_expr: &'tcx Expr<'_>,
-    else_expr: &'tcx Expr<'_>,
+    let_expr: &'tcx Expr<'tcx>,
+    then_expr: &'tcx Expr<'tcx>,
+    else_expr: &'tcx Expr<'tcx>,
     ty: Ty<'tcx>,
 ) {
     if let PatKind::TupleStruct(ref qpath, [unwrap_pat], _) = let_pat.kind
@@ -69,12 +73,43 @@ fn check_and_lint<'tcx>(
         && let Some(ty_name) = find_type_name(cx, ty)
         && let Some(or_body_snippet) = else_expr.span.get_source_text(cx)
         && let Some(indent) = indent_of(cx, expr.span)
-        && ConstEvalCtxt::new(cx).eval_simple(else_expr).is_some()
     {
-        lint(cx, expr, let_expr, ty_name, &or_body_snippet, indent);
+        let method = if ConstEvalCtxt::new(cx).eval_simple(else_expr).is_some() {
+            "unwrap_or"
+        } else if is_movable_default(cx, let_expr, else_expr) {
+            if switch_to_eager_eval(cx, else_expr) {
+                "unwrap_or"
+            } else {
+                "unwrap_or_else"
+            }
+        } else {
+            return;
+        };
+        lint(cx, expr, let_expr, ty_name, method, &or_body_snippet, indent);
     }
 }
 
+/// Checks whether a non-constant default can be moved into `unwrap_or`/`unwrap_or_else` as is.
+///
+/// The default has to be a single expression which doesn't evaluate to a reference (it could point
+/// to a temporary) and doesn't use any of the locals the scrutinee refers to (they would be
+/// borrowed or moved by the call).
+fn is_movable_default<'tcx>(cx: &LateContext<'tcx>, scrutinee: &'tcx Expr<'tcx>, default: &'tcx Expr<'tcx>) -> bool {
+    !matches!(peel_blocks(default).kind, ExprKind::Block(..))
+        && !contains_return_break_continue_macro(default)
+        && !cx.typeck_results().expr_ty(default).is_ref()
+        && for_each_expr_without_closures(scrutinee, |e| {
+            if let Some(id) = path_to_local(e)
+                && is_local_used(cx, default, id)
+            {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_none()
+}
+
 fn find_type_name<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<&'static str> {
     if is_type_diagnostic_item(cx, ty, sym::Option) {
         Some("Option")
@@ -110,6 +145,7 @@ fn lint<'tcx>(
     expr: &Expr<'tcx>,
     scrutinee: &'tcx Expr<'_>,
     ty_name: &str,
+    method: &str,
     or_body_snippet: &str,
     indent: usize,
 ) {
@@ -121,9 +157,13 @@ fn lint<'tcx>(
         cx,
         MANUAL_UNWRAP_OR,
         expr.span,
-        format!("this pattern reimplements `{ty_name}::unwrap_or`"),
+        format!("this pattern reimplements `{ty_name}::{method}`"),
         "replace with",
-        format!("{suggestion}.unwrap_or({reindented_or_body})",),
+        if method == "unwrap_or_else" {
+            format!("{suggestion}.unwrap_or_else(|| {reindented_or_body})")
+        } else {
+            format!("{suggestion}.{method}({reindented_or_body})")
+        },
         app,
     );
 }
@@ -188,7 +188,11 @@ pub struct SuggInfo<'a> {
 
 // Checks whether the expression could be passed as a function, or whether a closure is needed.
 // Returns the function to be passed to `map` if it exists.
-fn can_pass_as_func<'tcx>(cx: &LateContext<'tcx>, binding: HirId, expr: &'tcx Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+pub(super) fn can_pass_as_func<'tcx>(
+    cx: &LateContext<'tcx>,
+    binding: HirId,
+    expr: &'tcx Expr<'_>,
+) -> Option<&'tcx Expr<'tcx>> {
     match expr.kind {
         ExprKind::Call(func, [arg])
             if path_to_local_id(arg, binding)
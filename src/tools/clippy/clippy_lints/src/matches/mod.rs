@@ -700,7 +700,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Finds patterns that reimplement `Option::unwrap_or` or `Result::unwrap_or`.
+    /// Finds patterns that reimplement `Option::unwrap_or` or `Result::unwrap_or`, or their
+    /// lazily evaluated `unwrap_or_else` counterparts when the default isn't a constant.
     ///
     /// ### Why is this bad?
     /// Concise code helps focusing on behavior instead of boilerplate.
@@ -895,7 +896,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of `match` which could be implemented using `map`
+    /// Checks for usage of `match` which could be implemented using `map`, on both `Option`
+    /// and `Result`.
     ///
     /// ### Why is this bad?
     /// Using the `map` method is clearer and more concise.
@@ -1,7 +1,8 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::{is_expr_untyped_identity_function, is_trait_method};
+use clippy_utils::{is_expr_untyped_identity_function, is_trait_method, path_to_local_id, peel_blocks};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
+use rustc_hir::{ExprKind, PatKind};
 use rustc_lint::LateContext;
 use rustc_span::{sym, Span};
 
@@ -14,7 +15,9 @@ pub(super) fn check<'tcx>(
     flat_map_arg: &'tcx hir::Expr<'_>,
     flat_map_span: Span,
 ) {
-    if is_trait_method(cx, expr, sym::Iterator) && is_expr_untyped_identity_function(cx, flat_map_arg) {
+    if is_trait_method(cx, expr, sym::Iterator)
+        && (is_expr_untyped_identity_function(cx, flat_map_arg) || is_into_iter_closure(cx, flat_map_arg))
+    {
         span_lint_and_sugg(
             cx,
             FLAT_MAP_IDENTITY,
@@ -26,3 +29,22 @@ pub(super) fn check<'tcx>(
         );
     }
 }
+
+/// Checks if `expr` is a closure of the form `|x| x.into_iter()`, calling `IntoIterator::into_iter`
+/// on the parameter itself, as `flatten` would.
+fn is_into_iter_closure(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    if let ExprKind::Closure(closure) = expr.kind
+        && let body = cx.tcx.hir().body(closure.body)
+        && let [param] = body.params
+        && let PatKind::Binding(_, id, _, None) = param.pat.kind
+        && let value = peel_blocks(body.value)
+        && let ExprKind::MethodCall(method, recv, [], _) = value.kind
+        && method.ident.name == sym::into_iter
+        && path_to_local_id(recv, id)
+        && cx.typeck_results().expr_adjustments(recv).is_empty()
+    {
+        is_trait_method(cx, value, sym::IntoIterator)
+    } else {
+        false
+    }
+}
@@ -1,15 +1,19 @@
-use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::source::snippet_with_context;
 use clippy_utils::ty::implements_trait;
 use clippy_utils::{is_diag_item_method, is_diag_trait_item, peel_middle_ty_refs};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
+use rustc_middle::ty::print::with_forced_trimmed_paths;
 use rustc_span::sym;
 
 use super::IMPLICIT_CLONE;
 
 pub fn check(cx: &LateContext<'_>, method_name: &str, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>) {
+    if method_name == "to_owned" && check_double_ref_to_owned(cx, expr, recv) {
+        return;
+    }
     if let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
         && is_clone_like(cx, method_name, method_def_id)
         && let return_type = cx.typeck_results().expr_ty(expr)
@@ -39,6 +43,43 @@ pub fn check(cx: &LateContext<'_>, method_name: &str, expr: &hir::Expr<'_>, recv
     }
 }
 
+/// Checks for `to_owned` called on a reference to a reference, e.g. a `&&str`. This resolves to
+/// `<&str as ToOwned>::to_owned`, which copies the inner reference instead of producing a `String`.
+fn check_double_ref_to_owned(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>) -> bool {
+    if let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && is_diag_trait_item(cx, method_def_id, sym::ToOwned)
+        && let recv_ty = cx.typeck_results().expr_ty(recv)
+        && let (inner_ty, ref_count) = peel_middle_ty_refs(recv_ty)
+        && ref_count > 1
+        && cx.typeck_results().expr_ty(expr).peel_refs() == inner_ty
+        && let Some(to_owned_trait) = cx.tcx.get_diagnostic_item(sym::ToOwned)
+        && implements_trait(cx, inner_ty, to_owned_trait, &[])
+    {
+        let mut app = Applicability::MaybeIncorrect;
+        let recv_snip = snippet_with_context(cx, recv.span, expr.span.ctxt(), "..", &mut app).0;
+        let derefs = "*".repeat(ref_count - 1);
+        span_lint_and_then(
+            cx,
+            IMPLICIT_CLONE,
+            expr.span,
+            with_forced_trimmed_paths!(format!(
+                "calling `to_owned` on a `{recv_ty}` only copies the inner reference"
+            )),
+            |diag| {
+                diag.span_suggestion(
+                    expr.span,
+                    "to get an owned value, dereference the receiver first",
+                    format!("({derefs}{recv_snip}).to_owned()"),
+                    app,
+                );
+                diag.help(format!("to copy the reference, use `*{recv_snip}` instead"));
+            },
+        );
+        return true;
+    }
+    false
+}
+
 /// Returns true if the named method can be used to clone the receiver.
 /// Note that `to_string` is not flagged by `implicit_clone`. So other lints that call
 /// `is_clone_like` and that do flag `to_string` must handle it separately. See, e.g.,
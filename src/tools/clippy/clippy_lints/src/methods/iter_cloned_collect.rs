@@ -1,9 +1,11 @@
 use crate::methods::utils::derefs_to_slice;
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
+use rustc_hir::LangItem;
 use rustc_lint::LateContext;
+use rustc_middle::ty;
 use rustc_span::sym;
 
 use super::ITER_CLONED_COLLECT;
@@ -27,3 +29,37 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, method_name: &str, expr: &hir:
         );
     }
 }
+
+/// Checks for `.chars().collect::<String>()` on a string slice or a `String`.
+pub(super) fn check_chars<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>, recv: &'tcx hir::Expr<'_>) {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    // Only peel a single reference: `to_owned` on a `&&str` would just copy the `&str`.
+    let recv_ty = match recv_ty.kind() {
+        ty::Ref(_, inner, _) => *inner,
+        _ => recv_ty,
+    };
+    // `clone` says what happens to a `String`, `to_owned` is only needed for a `str`.
+    let method = if recv_ty.is_str() {
+        "to_owned"
+    } else if is_type_lang_item(cx, recv_ty, LangItem::String) {
+        "clone"
+    } else {
+        return;
+    };
+    if is_type_lang_item(cx, cx.typeck_results().expr_ty(expr), LangItem::String)
+        && let Some(to_replace) = expr.span.trim_start(recv.span.source_callsite())
+    {
+        span_lint_and_sugg(
+            cx,
+            ITER_CLONED_COLLECT,
+            to_replace,
+            format!(
+                "called `chars().collect()` on a string to create a `String`. Calling `{method}()` is both faster and \
+            more readable"
+            ),
+            "try",
+            format!(".{method}()"),
+            Applicability::MachineApplicable,
+        );
+    }
+}
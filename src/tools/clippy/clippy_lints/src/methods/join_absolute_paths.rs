@@ -4,17 +4,18 @@
 use clippy_utils::ty::is_type_diagnostic_item;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind};
+use rustc_hir::Expr;
 use rustc_lint::LateContext;
 use rustc_span::symbol::sym;
 use rustc_span::Span;
 
+use super::utils::path_str_lit;
 use super::JOIN_ABSOLUTE_PATHS;
 
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, recv: &'tcx Expr<'tcx>, join_arg: &'tcx Expr<'tcx>, expr_span: Span) {
     let ty = cx.typeck_results().expr_ty(recv).peel_refs();
     if (is_type_diagnostic_item(cx, ty, sym::Path) || is_type_diagnostic_item(cx, ty, sym::PathBuf))
-        && let ExprKind::Lit(spanned) = expr_or_init(cx, join_arg).kind
+        && let Some(spanned) = path_str_lit(cx, expr_or_init(cx, join_arg))
         && let LitKind::Str(symbol, _) = spanned.node
         && let sym_str = symbol.as_str()
         && sym_str.starts_with(['/', '\\'])
@@ -1,10 +1,14 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::source::{indent_of, reindent_multiline, SpanRangeExt};
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::source::{indent_of, reindent_multiline, snippet_with_context, SpanRangeExt};
+use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{is_res_lang_ctor, path_res, path_to_local_id};
+use clippy_utils::{
+    can_move_expr_to_closure, is_in_const_context, is_res_lang_ctor, is_wild, path_res, path_to_local_id, peel_blocks,
+};
 use rustc_errors::Applicability;
-use rustc_hir::LangItem::{ResultErr, ResultOk};
-use rustc_hir::{Expr, ExprKind, PatKind};
+use rustc_hir::LangItem::{OptionNone, OptionSome, ResultErr, ResultOk};
+use rustc_hir::{Arm, BindingMode, Expr, ExprKind, HirId, LangItem, Pat, PatKind};
 use rustc_lint::LateContext;
 use rustc_span::symbol::sym;
 
@@ -57,3 +61,118 @@ fn is_ok_wrapping(cx: &LateContext<'_>, map_expr: &Expr<'_>) -> bool {
         _ => false,
     }
 }
+
+/// Checks for `match opt { Some(x) => Ok(x), None => Err(e) }` and
+/// `match res { Ok(x) => Some(x), Err(_) => None }`.
+pub(super) fn check_match<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    scrutinee: &'tcx Expr<'tcx>,
+    arms: &'tcx [Arm<'tcx>],
+) {
+    let [first, second] = arms else {
+        return;
+    };
+    // `ok_or` and `ok` can't be called in const contexts
+    if first.guard.is_some() || second.guard.is_some() || is_in_const_context(cx) {
+        return;
+    }
+    let scrutinee_ty = cx.typeck_results().expr_ty(scrutinee);
+    let ctxt = expr.span.ctxt();
+    let mut app = Applicability::MachineApplicable;
+
+    if is_type_diagnostic_item(cx, scrutinee_ty, sym::Option)
+        && let Some((_, none_arm)) = [(first, second), (second, first)]
+            .into_iter()
+            .find(|(some_arm, _)| is_wrapping_arm(cx, some_arm, OptionSome, ResultOk))
+        && (is_lang_ctor_pat(cx, none_arm.pat, OptionNone) || is_trailing_wild(none_arm, second))
+        && let Some(err_arg) = err_arg(cx, none_arm.body)
+        // `?` and `return` would change meaning inside of the `ok_or_else` closure
+        && can_move_expr_to_closure(cx, err_arg).is_some()
+    {
+        let recv = Sugg::hir_with_context(cx, scrutinee, ctxt, "..", &mut app).maybe_par();
+        let err_arg_snippet = snippet_with_context(cx, err_arg.span, ctxt, "..", &mut app).0;
+        let sugg = if switch_to_eager_eval(cx, err_arg) {
+            format!("{recv}.ok_or({err_arg_snippet})")
+        } else {
+            format!("{recv}.ok_or_else(|| {err_arg_snippet})")
+        };
+        span_lint_and_sugg(
+            cx,
+            MANUAL_OK_OR,
+            expr.span,
+            "this pattern reimplements `Option::ok_or`",
+            "replace with",
+            sugg,
+            app,
+        );
+    } else if is_type_diagnostic_item(cx, scrutinee_ty, sym::Result)
+        && let Some((_, err_arm)) = [(first, second), (second, first)]
+            .into_iter()
+            .find(|(ok_arm, _)| is_wrapping_arm(cx, ok_arm, ResultOk, OptionSome))
+        && (is_err_wild_pat(cx, err_arm.pat) || is_trailing_wild(err_arm, second))
+        && is_res_lang_ctor(cx, path_res(cx, peel_blocks(err_arm.body)), OptionNone)
+    {
+        let recv = Sugg::hir_with_context(cx, scrutinee, ctxt, "..", &mut app).maybe_par();
+        span_lint_and_sugg(
+            cx,
+            MANUAL_OK_OR,
+            expr.span,
+            "this pattern reimplements `Result::ok`",
+            "replace with",
+            format!("{recv}.ok()"),
+            app,
+        );
+    }
+}
+
+/// Checks for an arm like `Some(x) => Ok(x)`, where `pat_ctor` is `Some` and `body_ctor` is `Ok`.
+fn is_wrapping_arm(cx: &LateContext<'_>, arm: &Arm<'_>, pat_ctor: LangItem, body_ctor: LangItem) -> bool {
+    if let PatKind::TupleStruct(ref qpath, [inner], _) = arm.pat.kind
+        && is_res_lang_ctor(cx, cx.qpath_res(qpath, arm.pat.hir_id), pat_ctor)
+        && let Some(binding) = plain_binding(inner)
+        && let ExprKind::Call(callee, [arg]) = peel_blocks(arm.body).kind
+        && is_res_lang_ctor(cx, path_res(cx, callee), body_ctor)
+    {
+        path_to_local_id(arg, binding)
+    } else {
+        false
+    }
+}
+
+fn plain_binding(pat: &Pat<'_>) -> Option<HirId> {
+    if let PatKind::Binding(BindingMode::NONE, id, _, None) = pat.kind {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+/// Checks if `arm` is a `_` arm that comes last, so the other arm is matched first.
+fn is_trailing_wild(arm: &Arm<'_>, last: &Arm<'_>) -> bool {
+    is_wild(arm.pat) && arm.hir_id == last.hir_id
+}
+
+fn is_lang_ctor_pat(cx: &LateContext<'_>, pat: &Pat<'_>, ctor: LangItem) -> bool {
+    matches!(pat.kind, PatKind::Path(ref qpath) if is_res_lang_ctor(cx, cx.qpath_res(qpath, pat.hir_id), ctor))
+}
+
+/// Checks for `Err(_)`.
+fn is_err_wild_pat(cx: &LateContext<'_>, pat: &Pat<'_>) -> bool {
+    if let PatKind::TupleStruct(ref qpath, [inner], _) = pat.kind {
+        is_res_lang_ctor(cx, cx.qpath_res(qpath, pat.hir_id), ResultErr) && is_wild(inner)
+    } else {
+        false
+    }
+}
+
+/// Returns `e` if `body` is `Err(e)`.
+fn err_arg<'tcx>(cx: &LateContext<'_>, body: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Call(callee, [arg]) = peel_blocks(body).kind
+        && is_res_lang_ctor(cx, path_res(cx, callee), ResultErr)
+    {
+        Some(arg)
+    } else {
+        None
+    }
+}
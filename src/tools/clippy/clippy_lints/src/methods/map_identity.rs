@@ -1,9 +1,10 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{is_expr_untyped_identity_function, is_trait_method};
+use clippy_utils::{is_diag_trait_item, is_expr_untyped_identity_function, is_trait_method, path_def_id};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
+use rustc_middle::ty;
 use rustc_span::{sym, Span};
 
 use super::MAP_IDENTITY;
@@ -21,7 +22,7 @@ pub(super) fn check(
     if (is_trait_method(cx, expr, sym::Iterator)
         || is_type_diagnostic_item(cx, caller_ty, sym::Result)
         || is_type_diagnostic_item(cx, caller_ty, sym::Option))
-        && is_expr_untyped_identity_function(cx, map_arg)
+        && (is_expr_untyped_identity_function(cx, map_arg) || is_noop_into(cx, map_arg))
         && let Some(sugg_span) = expr.span.trim_start(caller.span)
     {
         span_lint_and_sugg(
@@ -35,3 +36,17 @@ pub(super) fn check(
         );
     }
 }
+
+/// Checks if `expr` is the path `Into::into` converting a type into itself.
+fn is_noop_into(cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+    if let Some(def_id) = path_def_id(cx, expr)
+        && is_diag_trait_item(cx, def_id, sym::Into)
+        && let ty::FnDef(_, args) = cx.typeck_results().expr_ty(expr).kind()
+        && let [from, to] = args.as_slice()
+        && let (Some(from), Some(to)) = (from.as_type(), to.as_type())
+    {
+        from == to
+    } else {
+        false
+    }
+}
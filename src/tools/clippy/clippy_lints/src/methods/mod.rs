@@ -103,6 +103,7 @@
 mod string_extend_chars;
 mod string_lit_chars_any;
 mod suspicious_command_arg_space;
+mod suspicious_command_array_with_fixed_args;
 mod suspicious_map;
 mod suspicious_splitn;
 mod suspicious_to_owned;
@@ -815,7 +816,7 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of `flat_map(|x| x)`.
+    /// Checks for usage of `flat_map(|x| x)` and `flat_map(|x| x.into_iter())`.
     ///
     /// ### Why is this bad?
     /// Readability, this can be written more concisely by using `flatten`.
@@ -1417,10 +1418,12 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for the use of `.cloned().collect()` on slice to
-    /// create a `Vec`.
+    /// create a `Vec`, and of `.chars().collect()` on a string to
+    /// create a `String`.
     ///
     /// ### Why is this bad?
-    /// `.to_vec()` is clearer
+    /// `.to_vec()`, and `.to_owned()` or `.clone()` for strings, are clearer,
+    /// and copy the whole buffer at once instead of element by element.
     ///
     /// ### Example
     /// ```no_run
@@ -1982,7 +1985,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for instances of `map(f)` where `f` is the identity function.
+    /// Checks for instances of `map(f)` where `f` is the identity function,
+    /// or `Into::into` converting a type into itself.
     ///
     /// ### Why is this bad?
     /// It can be written more concisely without the call to `map`.
@@ -2029,10 +2033,13 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for the usage of `_.to_owned()`, `vec.to_vec()`, or similar when calling `_.clone()` would be clearer.
+    /// Also checks for `_.to_owned()` on a reference to a reference (e.g. a `&&str`), which only copies the
+    /// inner reference.
     ///
     /// ### Why is this bad?
     /// These methods do the same thing as `_.clone()` but may be confusing as
     /// to why we are calling `to_vec` on something that is already a `Vec` or calling `to_owned` on something that is already owned.
+    /// Likewise, `to_owned` on a `&&str` returns a `&str` rather than the `String` one would expect.
     ///
     /// ### Example
     /// ```no_run
@@ -2603,7 +2610,7 @@
 declare_clippy_lint! {
     /// ### What it does
     ///
-    /// Finds patterns that reimplement `Option::ok_or`.
+    /// Finds patterns that reimplement `Option::ok_or` or `Result::ok`.
     ///
     /// ### Why is this bad?
     ///
@@ -2613,17 +2620,26 @@
     /// ```no_run
     /// let foo: Option<i32> = None;
     /// foo.map_or(Err("error"), |v| Ok(v));
+    ///
+    /// let bar: Result<i32, &str> = Ok(1);
+    /// match bar {
+    ///     Ok(v) => Some(v),
+    ///     Err(_) => None,
+    /// };
     /// ```
     ///
     /// Use instead:
     /// ```no_run
     /// let foo: Option<i32> = None;
     /// foo.ok_or("error");
+    ///
+    /// let bar: Result<i32, &str> = Ok(1);
+    /// bar.ok();
     /// ```
     #[clippy::version = "1.49.0"]
     pub MANUAL_OK_OR,
     pedantic,
-    "finds patterns that can be encoded more concisely with `Option::ok_or`"
+    "finds patterns that can be encoded more concisely with `Option::ok_or` or `Result::ok`"
 }
 
 declare_clippy_lint! {
@@ -2860,6 +2876,8 @@
     /// Calling `push` with a root path at the start can overwrite the
     /// previous defined path.
     ///
+    /// See [`PathBuf::push`](https://doc.rust-lang.org/std/path/struct.PathBuf.html#method.push)
+    ///
     /// ### Example
     /// ```no_run
     /// use std::path::PathBuf;
@@ -3726,7 +3744,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for calls to `Path::join` that start with a path separator (`\\` or `/`).
+    /// Checks for calls to `Path::join` that start with a path separator (`\\` or `/`),
+    /// either as a string literal or as a `Path::new` call with a string literal.
     ///
     /// ### Why is this bad?
     /// If the argument to `Path::join` starts with a separator, it will overwrite
@@ -4126,6 +4145,36 @@
     "use of `map` returning the original item"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Command::new("sh").arg("-c").arg(cmd)` (or `bash`) where `cmd` is not a
+    /// string literal.
+    ///
+    /// ### Why restrict this?
+    /// The shell interprets the whole argument as a command line. If it is built from user
+    /// input, this allows command injection, since the input can contain `;`, `&&` or
+    /// other shell syntax.
+    ///
+    /// ### Known problems
+    /// The lint does not know where the argument comes from, so it also fires on commands
+    /// that are built from trusted data only.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let file = "input.txt";
+    /// std::process::Command::new("sh").arg("-c").arg(format!("cat {file}")).spawn().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let file = "input.txt";
+    /// std::process::Command::new("cat").arg(file).spawn().unwrap();
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub SUSPICIOUS_COMMAND_ARRAY_WITH_FIXED_ARGS,
+    restriction,
+    "non-literal command passed to `sh -c` or `bash -c`"
+}
+
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
@@ -4286,6 +4335,7 @@ pub fn new(conf: &'static Conf, format_args: FormatArgsStorage) -> Self {
     NEEDLESS_CHARACTER_ITERATION,
     MANUAL_INSPECT,
     UNNECESSARY_MIN_OR_MAX,
+    SUSPICIOUS_COMMAND_ARRAY_WITH_FIXED_ARGS,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -4343,6 +4393,9 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 };
                 lint_binary_expr_with_method_call(cx, &mut info);
             },
+            ExprKind::Match(scrutinee, arms, hir::MatchSource::Normal) => {
+                manual_ok_or::check_match(cx, expr, scrutinee, arms);
+            },
             _ => (),
         }
     }
@@ -4535,6 +4588,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 },
                 ("arg", [arg]) => {
                     suspicious_command_arg_space::check(cx, recv, arg, span);
+                    suspicious_command_array_with_fixed_args::check(cx, recv, arg);
                 },
                 ("as_deref" | "as_deref_mut", []) => {
                     needless_option_as_deref::check(cx, expr, recv, name);
@@ -4558,6 +4612,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         Some((name @ ("cloned" | "copied"), recv2, [], _, _)) => {
                             iter_cloned_collect::check(cx, name, expr, recv2);
                         },
+                        Some(("chars", recv2, [], _, _)) => {
+                            iter_cloned_collect::check_chars(cx, expr, recv2);
+                        },
                         Some(("map", m_recv, [m_arg], m_ident_span, _)) => {
                             map_collect_result_unit::check(cx, expr, m_recv, m_arg);
                             format_collect::check(cx, expr, m_arg, m_ident_span);
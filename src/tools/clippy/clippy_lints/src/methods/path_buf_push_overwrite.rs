@@ -2,18 +2,19 @@
 use clippy_utils::ty::is_type_diagnostic_item;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind};
+use rustc_hir::Expr;
 use rustc_lint::LateContext;
 use rustc_span::symbol::sym;
 use std::path::{Component, Path};
 
+use super::utils::path_str_lit;
 use super::PATH_BUF_PUSH_OVERWRITE;
 
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, arg: &'tcx Expr<'_>) {
     if let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
         && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
         && is_type_diagnostic_item(cx, cx.tcx.type_of(impl_id).instantiate_identity(), sym::PathBuf)
-        && let ExprKind::Lit(lit) = arg.kind
+        && let Some(lit) = path_str_lit(cx, arg)
         && let LitKind::Str(ref path_lit, _) = lit.node
         && let pushed_path = Path::new(path_lit.as_str())
         && let Some(pushed_path_lit) = pushed_path.to_str()
@@ -1,4 +1,4 @@
-use rustc_ast::ast::{LitIntType, LitKind};
+use rustc_ast::ast::LitKind;
 use rustc_data_structures::packed::Pu128;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
@@ -42,7 +42,7 @@ fn arg_is_seek_from_current<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>)
         // check if argument of `SeekFrom::Current` is `0`
         if args.len() == 1
             && let ExprKind::Lit(lit) = args[0].kind
-            && let LitKind::Int(Pu128(0), LitIntType::Unsuffixed) = lit.node
+            && let LitKind::Int(Pu128(0), _) = lit.node
         {
             return true;
         }
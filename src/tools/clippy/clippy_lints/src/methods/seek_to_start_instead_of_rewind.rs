@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::ty::implements_trait;
 use clippy_utils::{is_expr_used_or_unified, match_def_path, paths};
-use rustc_ast::ast::{LitIntType, LitKind};
+use rustc_ast::ast::LitKind;
 use rustc_data_structures::packed::Pu128;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
@@ -32,7 +32,7 @@ pub(super) fn check<'tcx>(
         && match_def_path(cx, def_id, &paths::STD_IO_SEEKFROM_START)
         && args1.len() == 1
         && let ExprKind::Lit(lit) = args1[0].kind
-        && let LitKind::Int(Pu128(0), LitIntType::Unsuffixed) = lit.node
+        && let LitKind::Int(Pu128(0), _) = lit.node
     {
         let method_call_span = expr.span.with_lo(name_span.lo());
         span_lint_and_then(
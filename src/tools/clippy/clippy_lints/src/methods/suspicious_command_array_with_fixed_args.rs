@@ -0,0 +1,43 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_ast::LitKind;
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+use std::path::Path;
+
+use super::SUSPICIOUS_COMMAND_ARRAY_WITH_FIXED_ARGS;
+
+const SHELLS: [&str; 2] = ["sh", "bash"];
+
+/// Checks `cmd.arg(arg)` where `cmd` is `Command::new("sh").arg("-c")`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, recv: &'tcx Expr<'_>, arg: &'tcx Expr<'_>) {
+    if !matches!(arg.kind, ExprKind::Lit(lit) if matches!(lit.node, LitKind::Str(..)))
+        && let ExprKind::MethodCall(seg, cmd, [flag], _) = recv.kind
+        && seg.ident.name.as_str() == "arg"
+        && is_str_lit(flag, "-c")
+        && let ExprKind::Call(func, [program]) = cmd.kind
+        && let ExprKind::Path(QPath::TypeRelative(_, new)) = func.kind
+        && new.ident.name == sym::new
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(cmd), sym::Command)
+        && let ExprKind::Lit(lit) = program.kind
+        && let LitKind::Str(program, _) = lit.node
+        && let Some(program) = Path::new(program.as_str()).file_name()
+        && SHELLS.iter().any(|shell| program == *shell)
+    {
+        span_lint_and_then(
+            cx,
+            SUSPICIOUS_COMMAND_ARRAY_WITH_FIXED_ARGS,
+            arg.span,
+            "non-literal command passed to a shell with `-c`",
+            |diag| {
+                diag.note("if the command contains user input, this allows executing arbitrary commands");
+                diag.help("consider running the program directly and passing its arguments with `arg` or `args`");
+            },
+        );
+    }
+}
+
+fn is_str_lit(expr: &Expr<'_>, value: &str) -> bool {
+    matches!(expr.kind, ExprKind::Lit(lit) if matches!(lit.node, LitKind::Str(s, _) if s.as_str() == value))
+}
@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::span_lint_and_suggestions;
 use clippy_utils::is_diag_trait_item;
 use clippy_utils::source::snippet_with_context;
 use rustc_errors::Applicability;
@@ -19,27 +19,21 @@ pub fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>) -
     {
         let mut app = Applicability::MaybeIncorrect;
         let recv_snip = snippet_with_context(cx, recv.span, expr.span.ctxt(), "..", &mut app).0;
-        span_lint_and_then(
+        span_lint_and_suggestions(
             cx,
             SUSPICIOUS_TO_OWNED,
             expr.span,
             with_forced_trimmed_paths!(format!(
                 "this `to_owned` call clones the {input_type} itself and does not cause the {input_type} contents to become owned"
             )),
-            |diag| {
-                diag.span_suggestion(
-                    expr.span,
+            [
+                (
+                    app,
                     "depending on intent, either make the Cow an Owned variant",
                     format!("{recv_snip}.into_owned()"),
-                    app,
-                );
-                diag.span_suggestion(
-                    expr.span,
-                    "or clone the Cow itself",
-                    format!("{recv_snip}.clone()"),
-                    app,
-                );
-            },
+                ),
+                (app, "or clone the Cow itself", format!("{recv_snip}.clone()")),
+            ],
         );
         return true;
     }
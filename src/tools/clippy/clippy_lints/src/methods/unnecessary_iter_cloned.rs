@@ -2,7 +2,7 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::higher::ForLoop;
 use clippy_utils::source::SpanRangeExt;
-use clippy_utils::ty::{get_iterator_item_ty, implements_trait};
+use clippy_utils::ty::implements_trait_with_assoc_type;
 use clippy_utils::visitors::for_each_expr_without_closures;
 use clippy_utils::{can_mut_borrow_both, fn_def_id, get_parent_expr, path_to_local};
 use core::ops::ControlFlow;
@@ -93,12 +93,11 @@ fn is_caller_or_fields_change(cx: &LateContext<'_>, body: &Expr<'_>, caller: &Ex
             && maybe_iter_method_name.ident.name == sym::iter
             && let Some(iterator_trait_id) = cx.tcx.get_diagnostic_item(sym::Iterator)
             && let receiver_ty = cx.typeck_results().expr_ty(receiver)
-            && implements_trait(cx, receiver_ty, iterator_trait_id, &[])
-            && let Some(iter_item_ty) = get_iterator_item_ty(cx, receiver_ty)
+            && let Some(iter_item_ty) = implements_trait_with_assoc_type(cx, receiver_ty, iterator_trait_id, "Item")
             && let Some(into_iterator_trait_id) = cx.tcx.get_diagnostic_item(sym::IntoIterator)
             && let collection_ty = cx.typeck_results().expr_ty(collection)
-            && implements_trait(cx, collection_ty, into_iterator_trait_id, &[])
-            && let Some(into_iter_item_ty) = cx.get_associated_type(collection_ty, into_iterator_trait_id, "Item")
+            && let Some(into_iter_item_ty) =
+                implements_trait_with_assoc_type(cx, collection_ty, into_iterator_trait_id, "Item")
             && iter_item_ty == into_iter_item_ty
             && let Some(collection_snippet) = collection.span.get_source_text(cx)
         {
@@ -3,7 +3,9 @@
 use clippy_config::msrvs::{self, Msrv};
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::source::{snippet, SpanRangeExt};
-use clippy_utils::ty::{get_iterator_item_ty, implements_trait, is_copy, is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::ty::{
+    implements_trait, implements_trait_with_assoc_type, is_copy, is_type_diagnostic_item, is_type_lang_item,
+};
 use clippy_utils::visitors::find_all_ret_expressions;
 use clippy_utils::{
     fn_def_id, get_parent_expr, is_diag_item_method, is_diag_trait_item, match_def_path, paths, peel_middle_ty_refs,
@@ -152,8 +154,7 @@ fn check_addr_of_expr(
             return true;
         }
         if let Some(deref_trait_id) = cx.tcx.get_diagnostic_item(sym::Deref)
-            && implements_trait(cx, receiver_ty, deref_trait_id, &[])
-            && cx.get_associated_type(receiver_ty, deref_trait_id, "Target") == Some(target_ty)
+            && implements_trait_with_assoc_type(cx, receiver_ty, deref_trait_id, "Target") == Some(target_ty)
             // Make sure that it's actually calling the right `.to_string()`, (#10033)
             // *or* this is a `Cow::into_owned()` call (which would be the wrong into_owned receiver (str != Cow)
             // but that's ok for Cow::into_owned specifically)
@@ -215,8 +216,7 @@ fn check_into_iter_call_arg(
         && is_into_iter(cx, callee_def_id)
         && let Some(iterator_trait_id) = cx.tcx.get_diagnostic_item(sym::Iterator)
         && let parent_ty = cx.typeck_results().expr_ty(parent)
-        && implements_trait(cx, parent_ty, iterator_trait_id, &[])
-        && let Some(item_ty) = get_iterator_item_ty(cx, parent_ty)
+        && let Some(item_ty) = implements_trait_with_assoc_type(cx, parent_ty, iterator_trait_id, "Item")
         && let Some(receiver_snippet) = receiver.span.get_source_text(cx)
     {
         if unnecessary_iter_cloned::check_for_loop_iter(cx, parent, method_name, receiver, true) {
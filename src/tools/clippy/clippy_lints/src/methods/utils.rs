@@ -1,7 +1,8 @@
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::{get_parent_expr, path_to_local_id, usage};
+use rustc_ast::ast::LitKind;
 use rustc_hir::intravisit::{walk_expr, Visitor};
-use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, Mutability, Pat, QPath, Stmt, StmtKind};
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, Lit, Mutability, Pat, QPath, Stmt, StmtKind};
 use rustc_lint::LateContext;
 use rustc_middle::hir::nested_filter;
 use rustc_middle::ty::{self, Ty};
@@ -46,6 +47,27 @@ fn may_slice<'a>(cx: &LateContext<'a>, ty: Ty<'a>) -> bool {
     }
 }
 
+/// Returns the string literal `expr` consists of, looking through a surrounding `Path::new` call,
+/// e.g. `"/bin"` for both `"/bin"` and `Path::new("/bin")`.
+pub(super) fn path_str_lit<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Lit> {
+    let expr = if let ExprKind::Call(func, [arg]) = expr.kind
+        && let ExprKind::Path(QPath::TypeRelative(_, segment)) = func.kind
+        && segment.ident.name == sym::new
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr).peel_refs(), sym::Path)
+    {
+        arg
+    } else {
+        expr
+    };
+    if let ExprKind::Lit(lit) = expr.kind
+        && let LitKind::Str(..) = lit.node
+    {
+        Some(lit)
+    } else {
+        None
+    }
+}
+
 /// The core logic of `check_for_loop_iter` in `unnecessary_iter_cloned.rs`, this function wraps a
 /// use of `CloneOrCopyVisitor`.
 pub(super) fn clone_or_copy_needed<'tcx>(
@@ -1,10 +1,12 @@
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::higher::{self, AssertArgs};
 use clippy_utils::is_in_test;
-use clippy_utils::macros::{find_assert_args, find_assert_eq_args, root_macro_call_first_node, PanicExpn};
+use clippy_utils::macros::PanicExpn;
+use clippy_utils::source::walk_span_to_context;
+use rustc_errors::Applicability;
 use rustc_hir::Expr;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::declare_lint_pass;
-use rustc_span::sym;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -50,43 +52,35 @@
 
 impl<'tcx> LateLintPass<'tcx> for MissingAssertMessage {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        let Some(macro_call) = root_macro_call_first_node(cx, expr) else {
+        let Some(assert) = higher::parse_assert(cx, expr) else {
             return;
         };
-        let single_argument = match cx.tcx.get_diagnostic_name(macro_call.def_id) {
-            Some(sym::assert_macro | sym::debug_assert_macro) => true,
-            Some(
-                sym::assert_eq_macro | sym::assert_ne_macro | sym::debug_assert_eq_macro | sym::debug_assert_ne_macro,
-            ) => false,
-            _ => return,
-        };
 
         // This lint would be very noisy in tests, so just ignore if we're in test context
         if is_in_test(cx.tcx, expr.hir_id) {
             return;
         }
 
-        let panic_expn = if single_argument {
-            let Some((_, panic_expn)) = find_assert_args(cx, expr, macro_call.expn) else {
-                return;
-            };
-            panic_expn
-        } else {
-            let Some((_, _, panic_expn)) = find_assert_eq_args(cx, expr, macro_call.expn) else {
-                return;
-            };
-            panic_expn
-        };
-
-        if let PanicExpn::Empty = panic_expn {
-            #[expect(clippy::collapsible_span_lint_calls, reason = "rust-clippy#7797")]
+        if let PanicExpn::Empty = assert.panic_expn {
             span_lint_and_then(
                 cx,
                 MISSING_ASSERT_MESSAGE,
-                macro_call.span,
+                assert.macro_call.span,
                 "assert without any message",
                 |diag| {
-                    diag.help("consider describing why the failing assert is problematic");
+                    let last_arg = match assert.args {
+                        AssertArgs::Condition(arg) | AssertArgs::Comparison(_, arg) => arg,
+                    };
+                    if let Some(span) = walk_span_to_context(last_arg.span, assert.macro_call.span.ctxt()) {
+                        diag.span_suggestion_verbose(
+                            span.shrink_to_hi(),
+                            "consider describing why the failing assert is problematic",
+                            ", \"..\"",
+                            Applicability::HasPlaceholders,
+                        );
+                    } else {
+                        diag.help("consider describing why the failing assert is problematic");
+                    }
                 },
             );
         }
@@ -1,6 +1,8 @@
-use clippy_utils::diagnostics::span_lint;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
-use rustc_hir::Expr;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Mutability, QPath};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, IntTy, Ty, UintTy};
 use rustc_session::declare_lint_pass;
@@ -24,6 +26,9 @@
     /// This lint cannot detect if the mutex is actually used
     /// for waiting before a critical section.
     ///
+    /// The suggested atomic has to be accessed with explicit loads and stores
+    /// instead of through `DerefMut`, so code using the value must be adjusted.
+    ///
     /// ### Example
     /// ```no_run
     /// # let y = true;
@@ -57,6 +62,9 @@
     /// This lint cannot detect if the mutex is actually used
     /// for waiting before a critical section.
     ///
+    /// Code using the integer through the `MutexGuard` has to be rewritten to
+    /// call `load` and `store` on the atomic instead.
+    ///
     /// ### Example
     /// ```no_run
     /// # use std::sync::Mutex;
@@ -78,22 +86,46 @@
 
 impl<'tcx> LateLintPass<'tcx> for Mutex {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        let ty = cx.typeck_results().expr_ty(expr);
-        if let ty::Adt(_, subst) = ty.kind() {
-            if is_type_diagnostic_item(cx, ty, sym::Mutex) {
-                let mutex_param = subst.type_at(0);
-                if let Some(atomic_name) = get_atomic_name(mutex_param) {
-                    let msg = format!(
-                        "consider using an `{atomic_name}` instead of a `Mutex` here; if you just want the locking \
-                         behavior and not the internal type, consider using `Mutex<()>`"
-                    );
-                    match *mutex_param.kind() {
-                        ty::Uint(t) if t != UintTy::Usize => span_lint(cx, MUTEX_INTEGER, expr.span, msg),
-                        ty::Int(t) if t != IntTy::Isize => span_lint(cx, MUTEX_INTEGER, expr.span, msg),
-                        _ => span_lint(cx, MUTEX_ATOMIC, expr.span, msg),
+        if let ExprKind::Call(func, args) = expr.kind
+            && let ty = cx.typeck_results().expr_ty(expr)
+            && let ty::Adt(_, subst) = ty.kind()
+            && is_type_diagnostic_item(cx, ty, sym::Mutex)
+            && let mutex_param = subst.type_at(0)
+            && let Some(atomic_name) = get_atomic_name(mutex_param)
+        {
+            let msg = format!(
+                "consider using an `{atomic_name}` instead of a `Mutex` here; if you just want the locking \
+                 behavior and not the internal type, consider using `Mutex<()>`"
+            );
+            let lint = match *mutex_param.kind() {
+                ty::Uint(t) if t != UintTy::Usize => MUTEX_INTEGER,
+                ty::Int(t) if t != IntTy::Isize => MUTEX_INTEGER,
+                _ => MUTEX_ATOMIC,
+            };
+            span_lint_and_then(cx, lint, expr.span, msg, |diag| {
+                if let ExprKind::Path(QPath::TypeRelative(_, segment)) = func.kind
+                    && segment.ident.name == sym::new
+                    && let [arg] = args
+                {
+                    let mut app = Applicability::MaybeIncorrect;
+                    let arg = Sugg::hir_with_context(cx, arg, expr.span.ctxt(), "..", &mut app);
+                    // `AtomicPtr::new` takes a `*mut T`
+                    let arg = if let ty::RawPtr(_, Mutability::Not) = mutex_param.kind() {
+                        format!("{}.cast_mut()", arg.maybe_par())
+                    } else {
+                        arg.to_string()
                     };
+                    diag.span_suggestion(
+                        expr.span,
+                        "try",
+                        format!("std::sync::atomic::{atomic_name}::new({arg})"),
+                        app,
+                    );
                 }
-            }
+                diag.note(
+                    "unlike `Mutex`, atomics do not implement `DerefMut` and are accessed with `load` and `store`",
+                );
+            });
         }
     }
 }
@@ -48,6 +48,14 @@ macro_rules! lint_double_comparison {
         (BinOpKind::And, BinOpKind::Le, BinOpKind::Ge) | (BinOpKind::And, BinOpKind::Ge, BinOpKind::Le) => {
             lint_double_comparison!(==);
         },
+        (BinOpKind::And, BinOpKind::Lt | BinOpKind::Le, BinOpKind::Ne)
+        | (BinOpKind::And, BinOpKind::Ne, BinOpKind::Lt | BinOpKind::Le) => {
+            lint_double_comparison!(<);
+        },
+        (BinOpKind::And, BinOpKind::Gt | BinOpKind::Ge, BinOpKind::Ne)
+        | (BinOpKind::And, BinOpKind::Ne, BinOpKind::Gt | BinOpKind::Ge) => {
+            lint_double_comparison!(>);
+        },
         _ => (),
     };
 }
@@ -288,8 +288,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for double comparisons that could be simplified to a single expression.
-    ///
+    /// Checks for double comparisons that could be simplified to a single expression,
+    /// such as `x < y || x == y` or `x < y && x != y`.
     ///
     /// ### Why is this bad?
     /// Readability.
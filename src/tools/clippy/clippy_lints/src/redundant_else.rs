@@ -9,6 +9,9 @@
     /// ### What it does
     /// Checks for `else` blocks that can be removed without changing semantics.
     ///
+    /// This includes a trailing `else if` without a final `else`, when all
+    /// the previous branches always break control flow.
+    ///
     /// ### Why is this bad?
     /// The `else` block adds unnecessary indentation and verbosity.
     ///
@@ -72,7 +75,17 @@ fn check_stmt(&mut self, cx: &EarlyContext<'_>, stmt: &Stmt) {
                     continue;
                 },
                 // else if without else
-                ExprKind::If(..) => return,
+                ExprKind::If(..) => {
+                    span_lint_and_help(
+                        cx,
+                        REDUNDANT_ELSE,
+                        els.span,
+                        "redundant else block",
+                        None,
+                        "remove the `else` and move the `if` out",
+                    );
+                    return;
+                },
                 // done
                 _ => break,
             }
@@ -12,6 +12,8 @@
     /// ### What it does
     /// Checks for constants and statics with an explicit `'static` lifetime.
     ///
+    /// References nested in arrays, slices, tuples and raw pointers are checked as well.
+    ///
     /// ### Why is this bad?
     /// Adding `'static` to every reference can create very
     /// complicated types.
@@ -61,6 +63,10 @@ fn visit_type(ty: &Ty, cx: &EarlyContext<'_>, reason: &'static str) {
                     Self::visit_type(tup_ty, cx, reason);
                 }
             },
+            // References behind raw pointers are `'static` by default as well
+            TyKind::Ptr(ref mut_ty) => {
+                Self::visit_type(&mut_ty.ty, cx, reason);
+            },
             // This is what we are looking for !
             TyKind::Ref(ref optional_lifetime, ref borrow_type) => {
                 // Match the 'static lifetime
@@ -0,0 +1,91 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::higher;
+use clippy_utils::source::snippet_with_applicability;
+use rustc_ast::Mutability;
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for slices of a range that are only used to iterate over them, either with
+    /// `.iter()`/`.iter_mut()` or in a `for` loop.
+    ///
+    /// ### Why restrict this?
+    /// Slicing panics if the range is out of bounds. Code that must not panic can use
+    /// `get(range)` instead and iterate over the result, which yields no items for an out of
+    /// bounds range.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let v = vec![1, 2, 3];
+    /// for x in v[1..3].iter() {
+    ///     println!("{x}");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let v = vec![1, 2, 3];
+    /// for x in v.get(1..3).into_iter().flatten() {
+    ///     println!("{x}");
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub SLICING_INSTEAD_OF_ITER_RANGE,
+    restriction,
+    "slicing a range only to iterate over it"
+}
+
+declare_lint_pass!(SlicingInsteadOfIterRange => [SLICING_INSTEAD_OF_ITER_RANGE]);
+
+impl<'tcx> LateLintPass<'tcx> for SlicingInsteadOfIterRange {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(for_loop) = higher::ForLoop::hir(expr) {
+            if let ExprKind::AddrOf(BorrowKind::Ref, mutability, indexed) = for_loop.arg.kind {
+                check_slicing(cx, for_loop.arg.span, indexed, mutability);
+            }
+        } else if let ExprKind::MethodCall(path, recv, [], _) = expr.kind {
+            let mutability = match path.ident.name {
+                sym::iter => Mutability::Not,
+                sym::iter_mut => Mutability::Mut,
+                _ => return,
+            };
+            check_slicing(cx, expr.span, recv, mutability);
+        }
+    }
+}
+
+fn check_slicing(cx: &LateContext<'_>, span: Span, indexed: &Expr<'_>, mutability: Mutability) {
+    if let ExprKind::Index(base, index, _) = indexed.kind
+        && !span.from_expansion()
+        && cx.typeck_results().expr_ty(indexed).is_slice()
+        && let Some(range) = higher::Range::hir(index)
+        // `..` can't be out of bounds
+        && (range.start.is_some() || range.end.is_some())
+    {
+        span_lint_and_then(
+            cx,
+            SLICING_INSTEAD_OF_ITER_RANGE,
+            span,
+            "slicing a range only to iterate over it",
+            |diag| {
+                let mut app = Applicability::MaybeIncorrect;
+                let base = snippet_with_applicability(cx, base.span, "..", &mut app);
+                let index = snippet_with_applicability(cx, index.span, "..", &mut app);
+                let get = match mutability {
+                    Mutability::Not => "get",
+                    Mutability::Mut => "get_mut",
+                };
+                diag.note("slicing panics if the range is out of bounds");
+                diag.span_suggestion(
+                    span,
+                    "if an out of bounds range should yield no items, use",
+                    format!("{base}.{get}({index}).into_iter().flatten()"),
+                    app,
+                );
+            },
+        );
+    }
+}
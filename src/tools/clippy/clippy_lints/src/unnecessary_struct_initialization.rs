@@ -1,9 +1,13 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet;
-use clippy_utils::ty::is_copy;
+use clippy_utils::ty::{implements_trait, is_copy};
+use clippy_utils::usage::local_used_after_expr;
 use clippy_utils::{get_parent_expr, path_to_local};
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
 use rustc_hir::{BindingMode, Expr, ExprField, ExprKind, Node, PatKind, Path, QPath, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
 use rustc_session::declare_lint_pass;
 
 declare_clippy_lint! {
@@ -33,6 +37,9 @@
     /// The struct literal ``S { ..a }`` in the assignment to ``b`` could be replaced
     /// with just ``a``.
     ///
+    /// If all fields are `Copy` but the struct is not, and the source is used
+    /// afterwards, `.clone()` is suggested instead.
+    ///
     /// ### Known Problems
     /// Has false positives when the base is a place expression that cannot be
     /// moved out of, see [#10547](https://github.com/rust-lang/rust-clippy/issues/10547).
@@ -58,34 +65,67 @@ fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
 
         let field_path = same_path_in_all_fields(cx, expr, fields);
 
-        let sugg = match (field_path, base) {
+        let (sugg, source) = match (field_path, base) {
             (Some(&path), None) => {
                 // all fields match, no base given
-                path.span
+                let source = if let Res::Local(id) = path.res { Some(id) } else { None };
+                (path.span, source)
             },
             (Some(path), Some(base)) if base_is_suitable(cx, expr, base) && path_matches_base(path, base) => {
                 // all fields match, has base: ensure that the path of the base matches
-                base.span
+                (base.span, path_to_local(base))
             },
             (None, Some(base)) if fields.is_empty() && base_is_suitable(cx, expr, base) => {
                 // just the base, no explicit fields
-                base.span
+                (base.span, path_to_local(base))
             },
             _ => return,
         };
 
+        let mut sugg = snippet(cx, sugg, "..").into_owned();
+        let mut applicability = Applicability::MachineApplicable;
+        let ty = cx.typeck_results().expr_ty(expr);
+        if let Some(source) = source
+            && !is_copy(cx, ty)
+            && has_only_copy_fields(cx, ty)
+            && local_used_after_expr(cx, source, expr)
+        {
+            // The struct literal only copies the fields out of the source, which is used
+            // afterwards. Using the source itself would move it.
+            if !cx
+                .tcx
+                .lang_items()
+                .clone_trait()
+                .map_or(false, |clone| implements_trait(cx, ty, clone, &[]))
+            {
+                return;
+            }
+            sugg.push_str(".clone()");
+            applicability = Applicability::MaybeIncorrect;
+        }
+
         span_lint_and_sugg(
             cx,
             UNNECESSARY_STRUCT_INITIALIZATION,
             expr.span,
             "unnecessary struct building",
             "replace with",
-            snippet(cx, sugg, "..").into_owned(),
-            rustc_errors::Applicability::MachineApplicable,
+            sugg,
+            applicability,
         );
     }
 }
 
+/// Checks if all fields of the struct type `ty` are `Copy`, in which case building it from the
+/// fields of another instance does not move out of that instance.
+fn has_only_copy_fields<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    if let ty::Adt(def, args) = ty.kind() {
+        def.all_fields().all(|field| is_copy(cx, field.ty(cx.tcx, args)))
+    } else {
+        false
+    }
+}
+
 fn base_is_suitable(cx: &LateContext<'_>, expr: &Expr<'_>, base: &Expr<'_>) -> bool {
     if !check_references(cx, expr, base) {
         return false;
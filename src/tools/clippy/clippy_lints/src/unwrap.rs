@@ -19,6 +19,8 @@
     /// ### What it does
     /// Checks for calls of `unwrap[_err]()` that cannot fail.
     ///
+    /// This includes `unwrap[_err]_unchecked()` calls.
+    ///
     /// ### Why is this bad?
     /// Using `if let` or `match` is more idiomatic.
     ///
@@ -50,6 +52,8 @@
     /// ### What it does
     /// Checks for calls of `unwrap[_err]()` that will always fail.
     ///
+    /// This includes `unwrap[_err]_unchecked()` calls, which are undefined behavior instead.
+    ///
     /// ### Why is this bad?
     /// If panicking is desired, an explicit `panic!()` should be used.
     ///
@@ -307,8 +311,16 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
             if let ExprKind::MethodCall(method_name, self_arg, ..) = expr.kind
                 && let (self_arg, as_ref_kind) = consume_option_as_ref(self_arg)
                 && let Some(id) = path_to_local(self_arg)
-                && [sym::unwrap, sym::expect, sym!(unwrap_err)].contains(&method_name.ident.name)
-                && let call_to_unwrap = [sym::unwrap, sym::expect].contains(&method_name.ident.name)
+                && [
+                    sym::unwrap,
+                    sym::expect,
+                    sym!(unwrap_unchecked),
+                    sym!(unwrap_err),
+                    sym!(unwrap_err_unchecked),
+                ]
+                .contains(&method_name.ident.name)
+                && let call_to_unwrap = [sym::unwrap, sym::expect, sym!(unwrap_unchecked)]
+                    .contains(&method_name.ident.name)
                 && let Some(unwrappable) = self.unwrappables.iter()
                     .find(|u| u.local_id == id)
                 // Span contexts should not differ with the conditional branch
@@ -360,12 +372,18 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
                         },
                     );
                 } else {
+                    // The unchecked variants don't panic, they are undefined behavior instead.
+                    let outcome = if method_name.ident.as_str().ends_with("_unchecked") {
+                        "is always undefined behavior"
+                    } else {
+                        "will always panic"
+                    };
                     span_lint_hir_and_then(
                         self.cx,
                         PANICKING_UNWRAP,
                         expr.hir_id,
                         expr.span,
-                        format!("this call to `{}()` will always panic", method_name.ident.name),
+                        format!("this call to `{}()` {outcome}", method_name.ident.name),
                         |diag| {
                             diag.span_label(unwrappable.check.span, "because of this check");
                         },
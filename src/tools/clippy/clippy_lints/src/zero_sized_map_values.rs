@@ -17,6 +17,9 @@
     /// containing zero sized values is effectively a set. Using a set in that case improves
     /// readability and communicates intent more clearly.
     ///
+    /// This also applies to maps whose type is inferred, e.g. `let map = HashMap::new();`
+    /// followed by `map.insert(key, ())`.
+    ///
     /// ### Known problems
     /// * A zero-sized type cannot be recovered later if it contains private fields.
     /// * This lints the signature of public items
@@ -48,7 +51,13 @@ fn check_ty<'tcx>(&mut self, cx: &LateContext<'tcx>, hir_ty: &hir::Ty<'tcx>) {
         if !hir_ty.span.from_expansion()
             && !in_trait_impl(cx, hir_ty.hir_id)
             && let ty = ty_from_hir_ty(cx, hir_ty)
-            && (is_type_diagnostic_item(cx, ty, sym::HashMap) || is_type_diagnostic_item(cx, ty, sym::BTreeMap))
+            && let Some(set_name) = if is_type_diagnostic_item(cx, ty, sym::HashMap) {
+                Some("HashSet")
+            } else if is_type_diagnostic_item(cx, ty, sym::BTreeMap) {
+                Some("BTreeSet")
+            } else {
+                None
+            }
             && let ty::Adt(_, args) = ty.kind()
             && let ty = args.type_at(1)
             // Fixes https://github.com/rust-lang/rust-clippy/issues/7447 because of
@@ -65,7 +74,7 @@ fn check_ty<'tcx>(&mut self, cx: &LateContext<'tcx>, hir_ty: &hir::Ty<'tcx>) {
                 hir_ty.span,
                 "map with zero-sized value type",
                 None,
-                "consider using a set instead",
+                format!("consider using a `{set_name}` instead"),
             );
         }
     }
@@ -8,11 +8,13 @@
 //! Thank you!
 //! ~The `INTERNAL_METADATA_COLLECTOR` lint
 
+use clippy_config::types::LintLevel;
 use rustc_errors::{Applicability, Diag, DiagMessage, MultiSpan, SubdiagMessage};
 use rustc_hir::HirId;
-use rustc_lint::{LateContext, Lint, LintContext};
+use rustc_lint::{LateContext, Level, Lint, LintContext};
+use rustc_middle::lint::{in_external_macro, lint_level};
 use rustc_span::Span;
-use std::env;
+use std::{cmp, env};
 
 fn docs_link(diag: &mut Diag<'_, ()>, lint: &'static Lint) {
     if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
@@ -283,6 +285,45 @@ pub fn span_lint_hir_and_then(
     });
 }
 
+/// Like [`span_lint_hir_and_then`], but emits the lint at the given `level` instead of the level
+/// it has at `hir_id`.
+///
+/// This should only be used if the user explicitly configured the level for this emission, for
+/// example with the `severity` of a `disallowed-methods` entry in `clippy.toml`. The lint is still
+/// suppressed if it is allowed or expected at `hir_id`, and `--cap-lints` still applies. Likewise,
+/// `forbid` and `--force-warn` take precedence over `level`. Nothing is emitted for code from an
+/// external macro, since the user has no way to change it.
+pub fn span_lint_hir_with_level_and_then(
+    cx: &LateContext<'_>,
+    lint: &'static Lint,
+    level: LintLevel,
+    hir_id: HirId,
+    sp: impl Into<MultiSpan>,
+    msg: impl Into<DiagMessage>,
+    f: impl FnOnce(&mut Diag<'_, ()>),
+) {
+    let sp = sp.into();
+    if sp.primary_spans().iter().any(|&sp| in_external_macro(cx.sess(), sp)) {
+        return;
+    }
+    let (node_level, src) = cx.tcx.lint_level_at_node(lint, hir_id);
+    let level = match node_level {
+        Level::Warn | Level::Deny => {
+            let level = match level {
+                LintLevel::Warn => Level::Warn,
+                LintLevel::Deny => Level::Deny,
+            };
+            cmp::min(level, cx.sess().opts.lint_cap.unwrap_or(Level::Forbid))
+        },
+        Level::Allow | Level::Expect(_) | Level::ForceWarn(_) | Level::Forbid => node_level,
+    };
+    lint_level(cx.sess(), lint, level, src, Some(sp), |diag| {
+        diag.primary_message(msg);
+        f(diag);
+        docs_link(diag, lint);
+    });
+}
+
 /// Add a span lint with a suggestion on how to fix it.
 ///
 /// These suggestions can be parsed by rustfix to allow it to automatically fix your code.
@@ -330,3 +371,51 @@ pub fn span_lint_and_sugg<T: LintContext>(
         diag.span_suggestion(sp, help.into(), sugg, applicability);
     });
 }
+
+/// Add a span lint with several alternative suggestions on how to fix it.
+///
+/// Each suggestion is given as `(applicability, help, sugg)` and replaces the whole `sp`. The
+/// suggestions are shown ordered by their applicability, the most applicable one first; the
+/// relative order of suggestions with the same applicability is kept.
+///
+/// Since rustfix applies every `MachineApplicable` suggestion, only the first one can keep that
+/// applicability. Any further `MachineApplicable` alternative is emitted as `MaybeIncorrect`.
+///
+/// NOTE: Lint emissions are always bound to a node in the HIR, see [`span_lint_and_sugg`].
+///
+/// # Example
+///
+/// ```ignore
+/// span_lint_and_suggestions(
+///     cx,
+///     SOME_LINT,
+///     expr.span,
+///     "manual saturating subtraction",
+///     [
+///         (Applicability::MachineApplicable, "try", format!("{a}.saturating_sub({b})")),
+///         (Applicability::MaybeIncorrect, "or", format!("{a}.checked_sub({b}).unwrap_or(0)")),
+///     ],
+/// );
+/// ```
+pub fn span_lint_and_suggestions<T: LintContext, H: Into<SubdiagMessage>>(
+    cx: &T,
+    lint: &'static Lint,
+    sp: Span,
+    msg: impl Into<DiagMessage>,
+    suggestions: impl IntoIterator<Item = (Applicability, H, String)>,
+) {
+    let mut suggestions: Vec<_> = suggestions.into_iter().collect();
+    suggestions.sort_by_key(|&(applicability, ..)| applicability);
+    span_lint_and_then(cx, lint, sp, msg.into(), |diag| {
+        let mut has_machine_applicable = false;
+        for (applicability, help, sugg) in suggestions {
+            let applicability = if applicability == Applicability::MachineApplicable && has_machine_applicable {
+                Applicability::MaybeIncorrect
+            } else {
+                applicability
+            };
+            has_machine_applicable |= applicability == Applicability::MachineApplicable;
+            diag.span_suggestion(sp, help, sugg, applicability);
+        }
+    });
+}
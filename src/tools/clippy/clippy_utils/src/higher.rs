@@ -3,6 +3,7 @@
 #![deny(clippy::missing_docs_in_private_items)]
 
 use crate::consts::{ConstEvalCtxt, Constant};
+use crate::macros::{find_assert_args, find_assert_eq_args, root_macro_call_first_node, MacroCall, PanicExpn};
 use crate::ty::is_type_diagnostic_item;
 use crate::{is_expn_of, match_def_path, paths};
 
@@ -488,3 +489,67 @@ pub fn get_vec_init_kind<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -
     }
     None
 }
+
+/// The kind of an assertion macro, not distinguishing between the `debug_` and normal variants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssertKind {
+    /// `assert!(cond)` or `debug_assert!(cond)`
+    Assert,
+    /// `assert_eq!(a, b)` or `debug_assert_eq!(a, b)`
+    AssertEq,
+    /// `assert_ne!(a, b)` or `debug_assert_ne!(a, b)`
+    AssertNe,
+}
+
+/// The asserted expressions of an assertion macro call.
+#[derive(Clone, Copy, Debug)]
+pub enum AssertArgs<'tcx> {
+    /// The condition of an `assert!` call
+    Condition(&'tcx Expr<'tcx>),
+    /// The compared operands of an `assert_eq!` or `assert_ne!` call
+    Comparison(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>),
+}
+
+/// A parsed call of one of the `assert!`, `assert_eq!` and `assert_ne!` macros or their `debug_`
+/// variants.
+#[derive(Debug)]
+pub struct AssertInfo<'tcx> {
+    /// The root call of the assertion macro
+    pub macro_call: MacroCall,
+    /// Which assertion macro is called
+    pub kind: AssertKind,
+    /// Whether this is one of the `debug_` variants
+    pub is_debug: bool,
+    /// The asserted expressions, with their spans pointing to the macro arguments
+    pub args: AssertArgs<'tcx>,
+    /// The custom panic message, [`PanicExpn::Empty`] if there is none
+    pub panic_expn: PanicExpn<'tcx>,
+}
+
+/// Parses `expr` as the expansion of an assertion macro call, see [`AssertInfo`].
+pub fn parse_assert<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'tcx>) -> Option<AssertInfo<'tcx>> {
+    let macro_call = root_macro_call_first_node(cx, expr)?;
+    let (kind, is_debug) = match cx.tcx.get_diagnostic_name(macro_call.def_id)? {
+        sym::assert_macro => (AssertKind::Assert, false),
+        sym::debug_assert_macro => (AssertKind::Assert, true),
+        sym::assert_eq_macro => (AssertKind::AssertEq, false),
+        sym::debug_assert_eq_macro => (AssertKind::AssertEq, true),
+        sym::assert_ne_macro => (AssertKind::AssertNe, false),
+        sym::debug_assert_ne_macro => (AssertKind::AssertNe, true),
+        _ => return None,
+    };
+    let (args, panic_expn) = if kind == AssertKind::Assert {
+        let (condition, panic_expn) = find_assert_args(cx, expr, macro_call.expn)?;
+        (AssertArgs::Condition(condition), panic_expn)
+    } else {
+        let (lhs, rhs, panic_expn) = find_assert_eq_args(cx, expr, macro_call.expn)?;
+        (AssertArgs::Comparison(lhs, rhs), panic_expn)
+    };
+    Some(AssertInfo {
+        macro_call,
+        kind,
+        is_debug,
+        args,
+        panic_expn,
+    })
+}
@@ -745,15 +745,14 @@ pub fn def_path_def_ids(tcx: TyCtxt<'_>, path: &[&str]) -> impl Iterator<Item =
     def_path_res(tcx, path).into_iter().filter_map(|res| res.opt_def_id())
 }
 
-/// Creates a map of disallowed items to the reason they were disallowed.
+/// Creates a map of disallowed items to their configuration entries.
 pub fn create_disallowed_map(
     tcx: TyCtxt<'_>,
     disallowed: &'static [DisallowedPath],
-) -> DefIdMap<(&'static str, Option<&'static str>)> {
+) -> DefIdMap<&'static DisallowedPath> {
     disallowed
         .iter()
-        .map(|x| (x.path(), x.path().split("::").collect::<Vec<_>>(), x.reason()))
-        .flat_map(|(name, path, reason)| def_path_def_ids(tcx, &path).map(move |id| (id, (name, reason))))
+        .flat_map(|x| def_path_def_ids(tcx, &x.path().split("::").collect::<Vec<_>>()).map(move |id| (id, x)))
         .collect()
 }
 
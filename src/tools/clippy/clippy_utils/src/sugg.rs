@@ -1096,6 +1096,17 @@ fn binop_maybe_par() {
         let sugg = Sugg::BinOp(AssocOp::Add, "(1 + 1)".into(), "(1 + 1)".into());
         assert_eq!("((1 + 1) + (1 + 1))", sugg.maybe_par().to_string());
     }
+
+    #[test]
+    fn unop_maybe_par() {
+        let sugg = Sugg::BinOp(AssocOp::Add, "a".into(), "b".into());
+        assert_eq!("*(a + b)", sugg.clone().deref().to_string());
+        assert_eq!("&(a + b)", sugg.clone().addr().to_string());
+        assert_eq!("&*(a + b)", sugg.addr_deref().to_string());
+
+        assert_eq!("*function_call()", SUGGESTION.deref().to_string());
+        assert_eq!("&mut function_call()", SUGGESTION.mut_addr().to_string());
+    }
     #[test]
     fn not_op() {
         use AssocOp::{Add, Equal, Greater, GreaterEqual, LAnd, LOr, Less, LessEqual, NotEqual};
@@ -312,6 +312,24 @@ pub fn implements_trait_with_env_from_iter<'tcx>(
         .is_ok_and(EvaluationResult::must_apply_modulo_regions)
 }
 
+/// Checks whether a type implements a trait without generic parameters and, if so, resolves
+/// the named associated type of that implementation, e.g. `Item` for `Iterator`.
+///
+/// Returns `None` if the type does not implement the trait or the associated type can't be
+/// normalized.
+pub fn implements_trait_with_assoc_type<'tcx>(
+    cx: &LateContext<'tcx>,
+    ty: Ty<'tcx>,
+    trait_id: DefId,
+    assoc_name: &str,
+) -> Option<Ty<'tcx>> {
+    if implements_trait(cx, ty, trait_id, &[]) {
+        cx.get_associated_type(ty, trait_id, assoc_name)
+    } else {
+        None
+    }
+}
+
 /// Checks whether this type implements `Drop`.
 pub fn has_drop<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
     match ty.ty_adt_def() {
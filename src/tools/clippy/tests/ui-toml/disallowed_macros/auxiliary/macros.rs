@@ -45,3 +45,10 @@ macro_rules! attr {
         $i
     };
 }
+
+#[macro_export]
+macro_rules! external_dbg {
+    () => {
+        dbg!()
+    };
+}
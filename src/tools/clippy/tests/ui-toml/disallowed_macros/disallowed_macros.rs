@@ -46,3 +46,13 @@ impl Y for S {
 
 #[derive(Derive)]
 struct Foo;
+
+fn severity() {
+    dbg!();
+    // the lint can still be allowed
+    #[allow(clippy::disallowed_macros)]
+    dbg!();
+    eprint!("warned");
+    // not linted in external macros
+    macros::external_dbg!();
+}
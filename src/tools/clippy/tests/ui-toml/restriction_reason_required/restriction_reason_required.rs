@@ -0,0 +1,4 @@
+//@error-in-other-file: the `disallowed-methods` entry `std::vec::Vec::leak` has no `reason`
+//@error-in-other-file: the `disallowed-types` entry `std::sync::Mutex` has no `reason`
+
+fn main() {}
@@ -29,6 +29,7 @@ fn implemented_method(&self) {}
 
 mod local_mod {
     pub fn f() {}
+    pub fn denied() {}
 }
 
 fn main() {
@@ -59,4 +60,44 @@ fn main() {
     s.method();
     s.provided_method();
     s.implemented_method();
+
+    let mut n = Num(1);
+    let _ = n + Num(2);
+    n += Num(3);
+    let _ = -n;
+    // builtin operators on primitives are not method calls
+    let _ = 1 + 2;
+    let _ = -1;
+
+    local_mod::denied();
+    // the lint can still be allowed
+    #[allow(clippy::disallowed_methods)]
+    local_mod::denied();
+    // not an error despite `-D warnings`
+    warned();
 }
+
+#[derive(Clone, Copy)]
+struct Num(i32);
+
+impl std::ops::Add for Num {
+    type Output = Num;
+    fn add(self, rhs: Num) -> Num {
+        Num(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::AddAssign for Num {
+    fn add_assign(&mut self, rhs: Num) {
+        self.0 += rhs.0;
+    }
+}
+
+impl std::ops::Neg for Num {
+    type Output = Num;
+    fn neg(self) -> Num {
+        Num(-self.0)
+    }
+}
+
+fn warned() {}
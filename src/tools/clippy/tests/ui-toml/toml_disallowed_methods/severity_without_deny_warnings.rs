@@ -0,0 +1,18 @@
+//@compile-flags: --crate-name conf_disallowed_methods -W warnings
+// Without `-D warnings`, a `severity` of "deny" turns the warning into an error.
+
+#![warn(clippy::disallowed_methods)]
+
+fn local_fn() {}
+
+mod local_mod {
+    pub fn denied() {}
+}
+
+fn warned() {}
+
+fn main() {
+    local_fn();
+    local_mod::denied();
+    warned();
+}
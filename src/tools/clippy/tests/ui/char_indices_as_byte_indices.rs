@@ -0,0 +1,24 @@
+#![allow(unused)]
+#![warn(clippy::char_indices_as_byte_indices)]
+
+fn main() {
+    let s = "héllo";
+    for (i, _) in s.char_indices() {
+        let _ = s.chars().nth(i);
+        //~^ ERROR: using a byte index from `char_indices` as a number of `char`s
+        let _: String = s.chars().skip(i).collect();
+        //~^ ERROR: using a byte index from `char_indices` as a number of `char`s
+        let _ = &s[i..];
+    }
+
+    let owned = String::from(s);
+    for (i, _) in owned.char_indices() {
+        let _: String = owned.chars().take(i).collect();
+        //~^ ERROR: using a byte index from `char_indices` as a number of `char`s
+    }
+
+    // `enumerate` yields `char` positions, which is fine.
+    for (i, _) in s.chars().enumerate() {
+        let _ = s.chars().nth(i);
+    }
+}
@@ -196,3 +196,23 @@ fn check_expect() {
         x.expect("an error message");
     }
 }
+
+fn unchecked() {
+    let x = Some(());
+    if x.is_some() {
+        unsafe { x.unwrap_unchecked() };
+        //~^ ERROR: called `unwrap_unchecked` on `x` after checking its variant with `is_some`
+    } else {
+        unsafe { x.unwrap_unchecked() };
+        //~^ ERROR: this call to `unwrap_unchecked()` is always undefined behavior
+    }
+    if x.is_none() {
+        unsafe { x.unwrap_unchecked() };
+        //~^ ERROR: this call to `unwrap_unchecked()` is always undefined behavior
+    }
+    let res: Result<(), ()> = Err(());
+    if res.is_err() {
+        unsafe { res.unwrap_err_unchecked() };
+        //~^ ERROR: called `unwrap_err_unchecked` on `res` after checking its variant with `is_err`
+    }
+}
@@ -0,0 +1,62 @@
+#![warn(clippy::doc_comments_on_private_items)]
+#![allow(dead_code)]
+
+/// Public function, does not lint
+pub fn public() {}
+
+/// Private function
+fn private() {}
+
+/** Private function with a block comment */
+fn private_block() {}
+
+/// Multiple lines
+/// of documentation
+struct PrivateStruct {
+    /// Private field
+    field: u32,
+}
+
+/// Public struct, does not lint
+pub struct PublicStruct {
+    /// Public field, does not lint
+    pub public_field: u32,
+    /// Private field
+    private_field: u32,
+}
+
+/// Public enum, does not lint
+pub enum PublicEnum {
+    /// Variant of a public enum, does not lint
+    A,
+}
+
+mod private_module {
+    /// Public, but not reachable from outside the crate
+    pub fn unreachable() {}
+}
+
+/// Docs of impl blocks are shown on the page of the type, does not lint
+impl PublicStruct {
+    /// Private method
+    fn private_method(&self) {}
+}
+
+trait PrivateTrait {
+    /// Private trait method
+    fn method(&self);
+}
+
+impl PrivateTrait for PublicStruct {
+    /// Trait impl items do not lint
+    fn method(&self) {}
+}
+
+fn main() {
+    ///! Handled by `suspicious_doc_comments`
+    #[allow(clippy::suspicious_doc_comments)]
+    fn local() {}
+
+    #[doc = "doc attributes are not comments"]
+    fn local_attr() {}
+}
@@ -27,4 +27,19 @@ fn main() {
     if x >= y && x <= y {
         // do something
     }
+    if x < y && x != y {
+        // do something
+    }
+    if x != y && x < y {
+        // do something
+    }
+    if x <= y && x != y {
+        // do something
+    }
+    if x > y && x != y {
+        // do something
+    }
+    if x != y && x >= y {
+        // do something
+    }
 }
@@ -26,4 +26,22 @@ fn drop(&mut self) {
     }
 }
 
+// shouldn't cause an error, the comment marks the impl as intentional
+struct Qux;
+
+impl Drop for Qux {
+    fn drop(&mut self) {
+        // Intentionally empty, `Qux` must not be destructured
+    }
+}
+
+// should error, only an explicit marker makes the impl intentional
+struct Quux;
+
+impl Drop for Quux {
+    fn drop(&mut self) {
+        // TODO
+    }
+}
+
 fn main() {}
@@ -66,4 +66,16 @@ fn next(&mut self) -> Option<Self::Item> {
     }
 
     for _ in S.into_iter::<u32>() {}
+
+    // `IntoIterator` is only implemented through `Deref`
+    struct W(T);
+    impl std::ops::Deref for W {
+        type Target = T;
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    let w = W(T);
+    for _ in w.into_iter() {}
 }
@@ -1,4 +1,4 @@
-#![allow(unused_imports, clippy::needless_return)]
+#![allow(unused_imports, clippy::needless_return, clippy::into_iter_on_ref)]
 #![warn(clippy::flat_map_identity)]
 
 use std::convert;
@@ -13,3 +13,16 @@ fn main() {
     let iterator = [[0, 1], [2, 3], [4, 5]].iter();
     let _ = iterator.flat_map(|x| return x);
 }
+
+fn into_iter() {
+    let iterator = [vec![0, 1], vec![2, 3]].into_iter();
+    let _ = iterator.flat_map(|x| x.into_iter());
+
+    let iterator = [[0, 1], [2, 3], [4, 5]].iter();
+    let _ = iterator.flat_map(|x| x.into_iter());
+
+    // don't lint: `x` is auto-dereferenced, `&&Vec<i32>` is not `IntoIterator`
+    let v = [vec![0, 1], vec![2, 3]];
+    let refs = [&v[0], &v[1]];
+    let _ = refs.iter().flat_map(|x| x.into_iter());
+}
@@ -28,4 +28,19 @@ fn main() {
     } else {
         println!("Bunny");
     }
+
+    let x = 3;
+    if !matches!(x, 1..=5) {
+        //~^ ERROR: unnecessary boolean `not` operation
+        println!("Out of range");
+    } else {
+        println!("In range");
+    }
+
+    // Negating `&&` or `||` is fine
+    if !(foo() && bla()) {
+        println!("Bugs");
+    } else {
+        println!("Bunny");
+    }
 }
@@ -109,10 +109,10 @@ fn main() {
     // issue #8227
     let pathbuf_ref = &pathbuf;
     let pathbuf_ref = &pathbuf_ref;
-    let _ = pathbuf_ref.to_owned(); // Don't lint. Returns `&PathBuf`
+    let _ = pathbuf_ref.to_owned(); // Only copies the `&PathBuf`
     let _ = pathbuf_ref.to_path_buf();
     let pathbuf_ref = &pathbuf_ref;
-    let _ = pathbuf_ref.to_owned(); // Don't lint. Returns `&&PathBuf`
+    let _ = pathbuf_ref.to_owned(); // Only copies the `&&PathBuf`
     let _ = pathbuf_ref.to_path_buf();
 
     struct NoClone;
@@ -124,4 +124,6 @@ fn to_owned(&self) -> Self {
     }
     let no_clone = &NoClone;
     let _ = no_clone.to_owned();
+    let str_ref: &&str = &"foo";
+    let _ = str_ref.to_owned();
 }
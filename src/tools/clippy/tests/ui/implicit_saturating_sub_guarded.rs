@@ -0,0 +1,39 @@
+//@no-rustfix: overlapping suggestions
+#![warn(clippy::implicit_saturating_sub)]
+
+fn guarded_sub(a: u32, b: u32, x: usize) {
+    let _ = if a > b { a - b } else { 0 };
+    let _ = if a >= b { a - b } else { 0 };
+    let _ = if b < a { a - b } else { 0 };
+    let _ = if a < b { 0 } else { a - b };
+    let _ = if x > 0 { x - 1 } else { 0 };
+    let _ = if x != 0 { x - 1 } else { 0 };
+    let _ = match x {
+        0 => 0,
+        _ => x - 1,
+    };
+    let _ = match x {
+        0 => 0,
+        n => n - 1,
+    };
+
+    // don't lint
+    let _ = if a > b { a - b } else { 1 };
+    let _ = if a > b { a - 1 } else { 0 };
+    let _ = if a < b { a - b } else { 0 };
+    // `saturating_sub` doesn't stop at zero for signed integers
+    let signed: i32 = 5;
+    let _ = if signed > 0 { signed - 1 } else { 0 };
+}
+
+fn guarded_sub_else_if(c: bool, a: u32, b: u32) -> u32 {
+    if c {
+        0
+    } else if a > b {
+        a - b
+    } else {
+        0
+    }
+}
+
+fn main() {}
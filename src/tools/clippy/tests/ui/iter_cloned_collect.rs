@@ -28,3 +28,18 @@ fn main() {
     // Issue #6703
     let _: Vec<isize> = v.iter().copied().collect();
 }
+
+fn chars() {
+    let s = "foo";
+    let _: String = s.chars().collect();
+    let owned = String::from("foo");
+    let _: String = owned.chars().collect();
+    let owned_ref = &owned;
+    let _: String = owned_ref.chars().collect();
+
+    // don't lint: `to_owned` on a `&&str` would return a `&str`
+    let s_ref = &s;
+    let _: String = s_ref.chars().collect();
+    // don't lint: not collecting into a `String`
+    let _: Vec<char> = s.chars().collect();
+}
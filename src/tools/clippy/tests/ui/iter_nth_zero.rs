@@ -26,6 +26,9 @@ fn main() {
     s3.insert(3);
     let mut iter2 = s3.iter();
     let _unwrapped = iter2.nth(0).unwrap();
+
+    // The turbofish on the receiver is kept
+    let _turbofish = std::iter::empty::<u8>().nth(0);
 }
 
 struct Issue9820;
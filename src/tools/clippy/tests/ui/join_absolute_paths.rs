@@ -27,4 +27,8 @@ fn main() {
 
     let path = Path::new("/bin");
     path.join("sh");
+
+    let path = PathBuf::from("/bin");
+    path.join(Path::new("/sh"));
+    //~^ ERROR: argument to `Path::join` starts with a path separator
 }
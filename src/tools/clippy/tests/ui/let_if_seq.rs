@@ -138,3 +138,31 @@ fn main() {
     }
     println!("{}", val.get());
 }
+
+fn match_assign(n: i32) {
+    let x;
+    //~^ ERROR: `match` is an expression
+    match n {
+        0 => {
+            x = 1;
+        },
+        _ if f() => {
+            g(n);
+            x = 2;
+        },
+        _ => {
+            x = 3;
+        },
+    }
+    println!("{x}");
+
+    // don't lint: not every arm assigns `y`
+    let y;
+    match n {
+        0 => {
+            y = 1;
+        },
+        _ => return,
+    }
+    println!("{y}");
+}
@@ -0,0 +1,86 @@
+#![warn(clippy::manual_expect)]
+#![allow(unused_variables, clippy::manual_assert)]
+
+fn option(opt: Option<u32>) -> u32 {
+    if opt.is_none() {
+        //~^ ERROR: manually panicking before calling `unwrap()`
+        panic!("no value");
+    }
+    opt.unwrap()
+}
+
+fn result(res: Result<u32, String>) {
+    if res.is_err() {
+        //~^ ERROR: manually panicking before calling `unwrap()`
+        panic!("no value");
+    }
+    let value = res.unwrap();
+}
+
+fn format_args(res: Result<u32, String>, id: u32) {
+    if res.is_err() {
+        //~^ ERROR: manually panicking before calling `unwrap()`
+        panic!("no value for {id}");
+    }
+    let value = res.unwrap();
+}
+
+fn option_format_args(opt: Option<u32>, id: u32) -> u32 {
+    if opt.is_none() {
+        //~^ ERROR: manually panicking before calling `unwrap()`
+        panic!("no value for {}", id);
+    }
+    opt.unwrap()
+}
+
+fn escaped_braces(opt: Option<u32>) -> u32 {
+    if opt.is_none() {
+        //~^ ERROR: manually panicking before calling `unwrap()`
+        panic!("no {{value}}");
+    }
+    opt.unwrap()
+}
+
+fn other_delimiters(opt: Option<u32>, res: Result<u32, String>) {
+    if opt.is_none() {
+        //~^ ERROR: manually panicking before calling `unwrap()`
+        panic!["no value"];
+    }
+    let value = opt.unwrap();
+
+    if res.is_err() {
+        //~^ ERROR: manually panicking before calling `unwrap()`
+        panic! {"no value"}
+    }
+    let value = res.unwrap();
+}
+
+fn no_lint(opt: Option<u32>, other: Option<u32>) {
+    // the checked value isn't the unwrapped one
+    if opt.is_none() {
+        panic!("no value");
+    }
+    let value = other.unwrap();
+
+    // something happens in between
+    if opt.is_none() {
+        panic!("no value");
+    }
+    println!("checked");
+    let value = opt.unwrap();
+
+    // the `if` does more than panic
+    if opt.is_none() {
+        println!("about to panic");
+        panic!("no value");
+    }
+    let value = opt.unwrap();
+
+    // no message to keep
+    if opt.is_none() {
+        panic!();
+    }
+    let value = opt.unwrap();
+}
+
+fn main() {}
@@ -210,6 +210,39 @@ fn in_block(a: &[i32]) -> Option<i32> {
     should_be_none
 }
 
+fn with_break(arr: Vec<u32>) -> Option<u32> {
+    let mut found = None;
+    for el in arr {
+        if el % 2 == 0 {
+            found = Some(el);
+            break;
+        }
+    }
+    found
+}
+
+fn with_break_typed(arr: &[u32]) -> Option<u32> {
+    let mut found: Option<u32> = None;
+    for &el in arr {
+        if el > 4 {
+            found = Some(el);
+            break;
+        }
+    }
+    found
+}
+
+fn with_break_mutated_after(arr: Vec<u32>) -> u32 {
+    let mut found = None;
+    for el in arr {
+        if el == 3 {
+            found = Some(el);
+            break;
+        }
+    }
+    *found.get_or_insert(0)
+}
+
 // Not handled yet
 fn mut_binding(v: Vec<String>) -> Option<String> {
     for mut s in v {
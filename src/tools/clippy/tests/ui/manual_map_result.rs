@@ -0,0 +1,42 @@
+#![warn(clippy::manual_map)]
+#![allow(clippy::needless_match, dead_code)]
+
+fn parse(s: &str) -> Result<u32, String> {
+    s.parse().map_err(|_| String::from("not a number"))
+}
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn main() {
+    let _ = match parse("1") {
+        Ok(x) => Ok(x + 1),
+        Err(e) => Err(e),
+    };
+
+    let _ = match parse("1") {
+        Err(e) => Err(e),
+        Ok(x) => Ok(double(x)),
+    };
+
+    let res: Result<u32, String> = Ok(1);
+    let _ = match res {
+        Ok(x) => Ok(x.to_string()),
+        Err(e) => Err(e),
+    };
+
+    // don't lint, the error is converted
+    let _: Result<u32, Box<dyn std::error::Error>> = match "1".parse::<u32>() {
+        Ok(x) => Ok(x + 1),
+        Err(e) => Err(e.into()),
+    };
+
+    // don't lint, the `Ok` arm returns early
+    fn early_return(res: Result<u32, String>) -> Result<u32, String> {
+        match res {
+            Ok(x) => Ok(if x > 1 { return Err(String::new()) } else { x }),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -35,10 +35,79 @@ fn main() {
     // not applicable, expr is not a `Result` value
     foo.map_or(42, |v| v);
 
-    // TODO patterns not covered yet
+    // match case
     match foo {
         Some(v) => Ok(v),
         None => Err("error"),
     };
+
+    // match case with a lazily evaluated error
+    match foo {
+        None => Err(make_err()),
+        Some(v) => Ok(v),
+    };
+
+    // `Result::ok` match case
+    let res: Result<i32, &str> = Ok(1);
+    match res {
+        Ok(v) => Some(v),
+        Err(_) => None,
+    };
+
+    // not applicable, the error is used
+    match res {
+        Ok(v) => Some(v),
+        Err(e) => {
+            println!("{e}");
+            None
+        },
+    };
+
+    // not applicable, `Some` arm doesn't wrap the value as is
+    match foo {
+        Some(v) => Ok(v + 1),
+        None => Err("error"),
+    };
+
+    // TODO patterns not covered yet
     foo.map_or_else(|| Err("error"), |v| Ok(v));
 }
+
+fn make_err() -> &'static str {
+    "error"
+}
+
+#[allow(unreachable_patterns)]
+fn wild_first(foo: Option<i32>, res: Result<i32, &str>) {
+    // not applicable, the wildcard arm comes first and matches everything
+    match foo {
+        _ => Err("error"),
+        Some(v) => Ok(v),
+    };
+    match res {
+        _ => None,
+        Ok(v) => Some(v),
+    };
+}
+
+const fn in_const(foo: Option<i32>) -> Result<i32, &'static str> {
+    // not applicable, `ok_or` isn't const
+    match foo {
+        Some(v) => Ok(v),
+        None => Err("error"),
+    }
+}
+
+#[allow(unreachable_code, clippy::diverging_sub_expression)]
+fn control_flow(foo: Option<i32>) -> Result<Result<i32, String>, String> {
+    // not applicable, `?` and `return` can't be moved into the `ok_or_else` closure
+    let _ = match foo {
+        Some(v) => Ok(v),
+        None => Err(Err::<String, String>(String::new())?),
+    };
+    let _ = match foo {
+        Some(v) => Ok(v),
+        None => Err(return Ok(Ok(0))),
+    };
+    Ok(Ok(0))
+}
@@ -28,4 +28,12 @@ fn main() {
     // Has side effects and therefore should not be matched
     let mut l = vec![12_u8, 34];
     let y = (l.pop().unwrap() << 3) + (l.pop().unwrap() >> 5);
+    // Non-constant shift amounts
+    let n = 3u32;
+    let y_u32_var = (x_u32 << n) | (x_u32 >> (32 - n));
+    let y_u64_var = (x_u64 >> n) | (x_u64 << (u64::BITS - n));
+    let x_u128 = 1u128;
+    let y_u128 = (x_u128 >> 3) | (x_u128 << 125);
+    // False positive - the amounts aren't complementary
+    let y_u32_var_false = (x_u32 << n) | (x_u32 >> (16 - n));
 }
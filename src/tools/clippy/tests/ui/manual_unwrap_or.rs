@@ -63,7 +63,7 @@ fn option_unwrap_or() {
     }
 
     // cases where the none arm isn't a constant expression
-    // are not linted due to potential ownership issues
+    // are not linted when they could cause ownership issues
 
     // ownership issue example, don't lint
     struct NonCopyable;
@@ -301,3 +301,35 @@ pub fn get_match(index: &HashMap<usize, Vec<RefName>>, id: usize) -> &[RefName]
 }
 
 fn main() {}
+
+fn non_const_default(fallback: i32) {
+    fn compute(n: i32) -> String {
+        n.to_string()
+    }
+
+    let _ = match Some(String::new()) {
+        Some(s) => s,
+        None => compute(fallback),
+    };
+
+    let _ = match Ok::<i32, &str>(1) {
+        Ok(i) => i,
+        Err(_) => fallback,
+    };
+
+    // don't lint, the default uses the scrutinee
+    let mut option: Option<i32> = None;
+    let _ = match option {
+        Some(i) => i,
+        None => option.insert(1).abs(),
+    };
+
+    // don't lint, the default has side effects besides computing the value
+    let _ = match Some(1) {
+        Some(i) => i,
+        None => {
+            println!("no value");
+            fallback
+        },
+    };
+}
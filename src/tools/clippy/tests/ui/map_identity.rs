@@ -65,3 +65,10 @@ fn issue11764() {
     // no match ergonomics for `(i32, i32)`
     let _ = x.iter().copied().map(|(x, y)| (x, y));
 }
+
+fn noop_into() {
+    let x = [1u32, 2, 3];
+    let _: Vec<u32> = x.iter().copied().map(Into::into).collect();
+    // don't lint: `u32` is converted into `u64`
+    let _: Vec<u64> = x.iter().copied().map(Into::into).collect();
+}
@@ -199,3 +199,37 @@ pub fn issue_12760<const N: usize>() {
         }
     }
 }
+
+/// This needs to be documented
+pub fn index_slice(v: &[u32]) -> u32 {
+    v[0]
+}
+
+/// This needs to be documented
+pub fn index_vec(v: Vec<u32>) -> u32 {
+    v[0]
+}
+
+/// This needs to be documented
+pub fn index_str(s: &str) -> &str {
+    &s[1..]
+}
+
+/// This is documented
+///
+/// # Panics
+///
+/// Panics if `v` is empty
+pub fn index_documented(v: &[u32]) -> u32 {
+    v[0]
+}
+
+/// This is okay because it is private
+fn index_private(v: &[u32]) -> u32 {
+    v[0]
+}
+
+/// This is okay because the index is evaluated at compile time
+pub fn index_in_const<const N: usize>() -> usize {
+    const { [1, 2, 3][N] }
+}
@@ -0,0 +1,23 @@
+//@compile-flags: --test
+#![warn(clippy::missing_panics_doc)]
+#![allow(clippy::unnecessary_literal_unwrap)]
+
+/// This needs to be documented
+pub fn unwrap() {
+    let result: Result<u32, ()> = Err(());
+    result.unwrap();
+}
+
+/// This is okay because it is a test function
+#[test]
+pub fn test_unwrap() {
+    let result: Result<u32, ()> = Err(());
+    result.unwrap();
+}
+
+/// This is okay because it is a test function
+#[test]
+pub fn test_index() {
+    let v = vec![1, 2, 3];
+    assert_eq!(v[0], 1);
+}
@@ -1,3 +1,4 @@
+//@no-rustfix
 #![warn(clippy::all)]
 #![warn(clippy::mutex_integer)]
 #![warn(clippy::mutex_atomic)]
@@ -32,10 +33,18 @@ fn main() {
     const X: i64 = 0;
     Mutex::new(X);
     //~^ ERROR: consider using an `AtomicI64` instead of a `Mutex` here; if you just wan
+    let _x: Mutex<bool> = Mutex::default();
+    //~^ ERROR: consider using an `AtomicBool` instead of a `Mutex` here; if you just wa
+    // only constructions are linted
+    let x = Mutex::new(Vec::<u8>::new());
+    drop(x);
 
     // there are no 128 atomics, so these two should not lint
     {
         Mutex::new(0u128);
         let _x: Mutex<i128> = Mutex::new(0);
     }
+    let p: *const u32 = &4u32;
+    Mutex::new(p);
+    //~^ ERROR: consider using an `AtomicPtr` instead of a `Mutex` here; if you just want
 }
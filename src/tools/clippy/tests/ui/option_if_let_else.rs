@@ -305,3 +305,10 @@ fn issue11893() {
         panic!("Haven't thought about this condition.");
     }
 }
+
+fn question_mark(opt: Option<u32>, res: Result<u32, ()>) -> Result<u32, ()> {
+    // `?` returns from the function, which a closure can't do.
+    let _ = if let Some(x) = opt { x + res? } else { 0 };
+    let _ = if let Some(x) = opt { x } else { res? };
+    Ok(0)
+}
@@ -82,12 +82,6 @@ fn main() {
     } else {
         println!("Who is wise? He that learns from every one.");
     }
-    // else if without else
-    if foo() {
-        return;
-    } else if foo() {
-        foo()
-    };
     // nested if return
     if foo() {
         if foo() {
@@ -154,6 +148,13 @@ fn main() {
     } else {
         1
     });
+    // else if without else
+    if foo() {
+        return;
+    } else if foo() {
+        //~^ ERROR: redundant else block
+        foo()
+    };
 }
 
 fn foo<T>() -> T {
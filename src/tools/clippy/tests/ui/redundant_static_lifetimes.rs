@@ -68,3 +68,7 @@ fn msrv_1_16() {
 fn msrv_1_17() {
     static V: &'static u8 = &17;
 }
+
+const VAR_PTR: *const &'static str = &"Test constant #3"; // ERROR: Consider removing 'static.
+
+const VAR_PTR_ARRAY: [*mut &'static u8; 1] = [std::ptr::null_mut()]; // ERROR: Consider removing 'static.
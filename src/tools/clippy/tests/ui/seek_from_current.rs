@@ -18,6 +18,8 @@ fn _msrv_1_51() -> io::Result<()> {
     f.write_all(b"Hi!")?;
     f.seek(SeekFrom::Current(0))?;
     f.seek(SeekFrom::Current(1))?;
+    f.seek(io::SeekFrom::Current(0))?;
+    f.seek(std::io::SeekFrom::Current(0i64))?;
     Ok(())
 }
 
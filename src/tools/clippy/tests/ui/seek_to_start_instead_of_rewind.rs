@@ -134,6 +134,8 @@ fn msrv_1_55() {
     write!(f, "{hello}").unwrap();
 
     f.seek(SeekFrom::Start(0));
+    f.seek(std::io::SeekFrom::Start(0));
+    f.seek(std::io::SeekFrom::Start(0u64));
 
     let mut buf = String::new();
     f.read_to_string(&mut buf).unwrap();
@@ -0,0 +1,27 @@
+#![warn(clippy::slicing_instead_of_iter_range)]
+
+fn main() {
+    let mut v = vec![1, 2, 3, 4];
+    let (start, end) = (1, 3);
+
+    for x in v[start..end].iter() {
+        println!("{x}");
+    }
+    for x in v[1..].iter_mut() {
+        *x += 1;
+    }
+    for x in &v[..end] {
+        println!("{x}");
+    }
+    let arr = [1, 2, 3];
+    let _ = arr[1..=2].iter().sum::<i32>();
+
+    // Don't lint
+    for x in v[..].iter() {
+        println!("{x}");
+    }
+    for x in &v {
+        println!("{x}");
+    }
+    let _ = v[1];
+}
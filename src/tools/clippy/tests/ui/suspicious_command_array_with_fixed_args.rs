@@ -0,0 +1,23 @@
+#![warn(clippy::suspicious_command_array_with_fixed_args)]
+
+use std::process::Command;
+
+fn run(user_input: &str) {
+    Command::new("sh").arg("-c").arg(user_input).spawn().unwrap();
+    //~^ ERROR: non-literal command passed to a shell with `-c`
+    Command::new("/bin/bash").arg("-c").arg(format!("echo {user_input}"));
+    //~^ ERROR: non-literal command passed to a shell with `-c`
+
+    // Literal commands are fine
+    Command::new("sh").arg("-c").arg("echo hello");
+    // Not a shell
+    Command::new("python").arg("-c").arg(user_input);
+    // Not `-c`
+    Command::new("sh").arg("-e").arg(user_input);
+    // Running the program directly is fine
+    Command::new("echo").arg(user_input);
+}
+
+fn main() {
+    run("hello");
+}
@@ -143,3 +143,21 @@ struct S1 {
     // Should not lint: `a` is not from `s`
     let s = S1 { a, b: s.b };
 }
+
+fn used_afterwards() {
+    #[derive(Clone)]
+    struct C {
+        a: u32,
+        b: u32,
+    }
+
+    // Should lint and suggest `.clone()`: `x` is only copied from, and used afterwards
+    let x = C { a: 1, b: 2 };
+    let y = C { a: x.a, b: x.b };
+    let _ = x.a;
+
+    // Should not lint: `W` does not implement `Clone`
+    let z = W { f1: 1, f2: 2 };
+    let _ = W { f1: z.f1, ..z };
+    let _ = z.f1;
+}
@@ -667,3 +667,19 @@ fn from(_: Val<V>) -> Self {
         }
     }
 }
+
+mod assoc_items {
+    struct Foo;
+
+    impl Foo {
+        const UNIT: Foo = Foo;
+    }
+
+    impl Iterator for Foo {
+        type Item = Foo;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            None
+        }
+    }
+}
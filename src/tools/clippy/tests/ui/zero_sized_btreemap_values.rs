@@ -77,4 +77,8 @@ fn main() {
 
     let _: BTreeMap<_, _> = std::iter::empty::<(String, ())>().collect();
     //~^ ERROR: map with zero-sized value type
+
+    let mut inferred = BTreeMap::new();
+    //~^ ERROR: map with zero-sized value type
+    inferred.insert(String::new(), ());
 }
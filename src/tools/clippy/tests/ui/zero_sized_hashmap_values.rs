@@ -77,4 +77,8 @@ fn main() {
 
     let _: HashMap<_, _> = std::iter::empty::<(String, ())>().collect();
     //~^ ERROR: map with zero-sized value type
+
+    let mut inferred = HashMap::new();
+    //~^ ERROR: map with zero-sized value type
+    inferred.insert(String::new(), ());
 }
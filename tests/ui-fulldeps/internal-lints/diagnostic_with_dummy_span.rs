@@ -0,0 +1,24 @@
+// Test the `rustc::diagnostic_with_dummy_span` internal lint
+//@ compile-flags: -Z unstable-options
+
+#![feature(rustc_private)]
+#![deny(rustc::diagnostic_with_dummy_span)]
+#![allow(rustc::untranslatable_diagnostic)]
+#![allow(rustc::diagnostic_outside_of_impl)]
+#![crate_type = "lib"]
+
+extern crate rustc_errors;
+extern crate rustc_span;
+
+use rustc_errors::{Diag, DiagCtxtHandle};
+use rustc_span::{Span, DUMMY_SP};
+
+pub fn f(dcx: DiagCtxtHandle<'_>, span: Span) {
+    dcx.struct_span_err(DUMMY_SP, "foo").emit(); //~ ERROR `DUMMY_SP`
+    dcx.struct_span_err(span, "foo").emit();
+}
+
+pub fn g(diag: &mut Diag<'_>, span: Span) {
+    diag.span_label(DUMMY_SP, "bar"); //~ ERROR `DUMMY_SP`
+    diag.span_label(span, "bar");
+}
@@ -0,0 +1,20 @@
+// Check that `#[export_name]` can't silently shadow well-known runtime symbols.
+
+#![crate_type = "lib"]
+
+#[export_name = "malloc"] //~ ERROR `#[export_name]` shadows the runtime symbol `malloc`
+pub extern "C" fn my_malloc(_size: usize) -> *mut u8 {
+    std::ptr::null_mut()
+}
+
+#[export_name = "memcpy"] //~ ERROR `#[export_name]` shadows the runtime symbol `memcpy`
+pub static MEMCPY: u8 = 0;
+
+#[allow(exported_runtime_symbols)]
+#[export_name = "free"]
+pub extern "C" fn my_free(_ptr: *mut u8) {}
+
+#[export_name = "my_alloc"]
+pub extern "C" fn my_alloc(_size: usize) -> *mut u8 {
+    std::ptr::null_mut()
+}
@@ -0,0 +1,11 @@
+// Check that a value moved in a `match` arm and used after the `match` gets a suggestion to clone
+// it before the move.
+
+fn main() {
+    let s = String::from("hello");
+    match 0 {
+        0 => drop(s),
+        _ => {}
+    }
+    let _t = s; //~ ERROR use of moved value: `s`
+}
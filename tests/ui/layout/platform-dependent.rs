@@ -0,0 +1,63 @@
+//! Tests for `TyAndLayout::is_platform_dependent`.
+
+#![feature(rustc_attrs)]
+#![crate_type = "lib"]
+
+#[rustc_layout(platform_dependent)]
+type Usize = usize; //~ ERROR: platform_dependent: true
+
+#[rustc_layout(platform_dependent)]
+type ConstPtr = *const u8; //~ ERROR: platform_dependent: true
+
+#[rustc_layout(platform_dependent)]
+type U64 = u64; //~ ERROR: platform_dependent: true
+
+#[rustc_layout(platform_dependent)]
+type U32 = u32; //~ ERROR: platform_dependent: false
+
+#[rustc_layout(platform_dependent)]
+struct OnlyU32 { //~ ERROR: platform_dependent: false
+    a: u32,
+    b: [u32; 2],
+}
+
+#[rustc_layout(platform_dependent)]
+struct ContainsUsize { //~ ERROR: platform_dependent: true
+    a: u32,
+    b: [usize; 2],
+}
+
+#[rustc_layout(platform_dependent)]
+#[repr(C)]
+enum ReprC { //~ ERROR: platform_dependent: true
+    A,
+    B,
+}
+
+#[rustc_layout(platform_dependent)]
+#[repr(usize)]
+enum ReprUsize { //~ ERROR: platform_dependent: true
+    A,
+    B,
+}
+
+#[rustc_layout(platform_dependent)]
+#[repr(isize)]
+enum ReprIsize { //~ ERROR: platform_dependent: true
+    A,
+    B,
+}
+
+#[rustc_layout(platform_dependent)]
+#[repr(u64)]
+enum ReprU64 { //~ ERROR: platform_dependent: true
+    A,
+    B,
+}
+
+#[rustc_layout(platform_dependent)]
+#[repr(u32)]
+enum ReprU32 { //~ ERROR: platform_dependent: false
+    A(u32),
+    B,
+}
@@ -0,0 +1,22 @@
+// Check that a `cdylib` which links the standard library dynamically is linted, since the
+// resulting library can't be loaded without the Rust runtime. The dependency is only available
+// as a dylib, which forces `std` to be linked dynamically as well. Nothing is reported when the
+// lint is allowed, or when the dynamic linkage was asked for with `-C prefer-dynamic`.
+
+//@ revisions: warn allow prefer_dynamic
+//@ build-pass
+//@ no-prefer-dynamic
+//@ aux-build:cdylib-dynamic-std-dep.rs
+//@ needs-dynamic-linking
+//@ ignore-musl - auxiliary crates are not built as dylibs
+//@[prefer_dynamic] compile-flags: -C prefer-dynamic
+
+#![crate_type = "cdylib"]
+#![cfg_attr(allow, allow(dynamic_std_in_cdylib))]
+
+extern crate cdylib_dynamic_std_dep;
+
+#[no_mangle]
+pub extern "C" fn foo() {
+    cdylib_dynamic_std_dep::bar();
+}
@@ -0,0 +1,18 @@
+//@ edition:2018
+#![feature(must_not_suspend)]
+#![deny(must_not_suspend)]
+
+#[must_not_suspend = "You gotta use Umm's, ya know?"]
+struct Umm {
+    _i: i64
+}
+
+async fn other() {}
+
+pub async fn uhoh(guard: Umm) { //~ ERROR `Umm` held across
+    other().await;
+    drop(guard);
+}
+
+fn main() {
+}
@@ -0,0 +1,27 @@
+//@ edition:2018
+//@ check-pass
+#![feature(must_not_suspend)]
+#![deny(must_not_suspend)]
+
+#[must_not_suspend = "You gotta use Umm's, ya know?"]
+struct Umm {
+    _i: i64
+}
+
+fn bar() -> Umm {
+    Umm {
+        _i: 1
+    }
+}
+
+async fn other() {}
+
+// The value is explicitly dropped before the suspend point, so it is not held across it.
+pub async fn uhoh() {
+    let guard = bar();
+    drop(guard);
+    other().await;
+}
+
+fn main() {
+}
@@ -0,0 +1,17 @@
+// Check that the desugaring of `format_args!` is shown as the macro call it originates from in
+// the HIR tree, instead of the expressions it is lowered to.
+
+//@ build-pass
+//@ compile-flags: -o - -Zunpretty=hir-tree
+//@ check-stdout
+//@ dont-check-compiler-stdout
+//@ dont-check-compiler-stderr
+//@ regex-error-pattern: FormatArgs\(\s*"format_args!\(\\"\{\} \{\}\\", a, b\)",\s*\)
+//@ regex-error-pattern: FormatArgs\(\s*"println!\(\\"\{a\}\\"\)",\s*\)
+
+fn main() {
+    let a = 1;
+    let b = 2;
+    let _ = format_args!("{} {}", a, b);
+    println!("{a}");
+}